@@ -0,0 +1,249 @@
+use crate::note::{Note, Pitch};
+use crate::scale::Scale;
+
+use super::voicing::stack;
+use super::Chord;
+
+// the order the tones of an arpeggio are played in.
+// UpDown turns around at the top without repeating it, Alternating goes back to the lowest tone after every
+// other one (C E C G C C), and InversionCycle plays the chord up from each of its tones in turn (C E G, E G C, G C E).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArpeggioPattern {
+    Up,
+    Down,
+    UpDown,
+    Alternating,
+    InversionCycle,
+}
+
+impl Chord {
+    // the chord's tones played one after the other across a number of octaves, starting from the bass in the given
+    // octave and ending on the bass the octaves above, e.g. C major up over two octaves from 4 is C4 E4 G4 C5 E5 G5 C6.
+    // every octave plays each tone once, so chords with extensions span more than an octave each time.
+    // inversions start from their bass, and a bass that isn't a chord tone is arpeggiated like one.
+    pub fn arpeggio(
+        &self,
+        pattern: ArpeggioPattern,
+        octave: i8,
+        octaves: usize,
+    ) -> Result<Vec<Pitch>, String> {
+        let notes = self.notes_checked().ok_or(format!(
+            "{} can't be spelled with at most two accidentals",
+            self.name()
+        ))?;
+        let notes = match notes.iter().position(|&note| note == self.bass()) {
+            Some(index) => [&notes[index..], &notes[..index]].concat(),
+            None => [&[self.bass()], &notes[..]].concat(),
+        };
+        arpeggiate(&notes, Pitch::new(self.bass(), octave), pattern, octaves)
+    }
+}
+
+impl Scale {
+    // an arpeggio of the triad, or of the seventh chord, built on every degree of the scale.
+    // each one starts from its degree in the ascending scale from the root in the given octave,
+    // so the arpeggios of C major from 4 start on C4, D4, E4 and so on up to B4.
+    // the scale's own thirds are stacked on every degree, even where they don't make a chord with a name.
+    pub fn arpeggios(
+        &self,
+        pattern: ArpeggioPattern,
+        sevenths: bool,
+        octave: i8,
+        octaves: usize,
+    ) -> Result<Vec<Vec<Pitch>>, String> {
+        let degrees = self.heptatonic_degrees()?;
+        let thirds: &[usize] = match sevenths {
+            true => &[0, 2, 4, 6],
+            false => &[0, 2, 4],
+        };
+        let starts = stack(Pitch::new(self.root(), octave), &degrees).ok_or(format!(
+            "The scale from octave {octave} goes past octave 127"
+        ))?;
+        starts
+            .into_iter()
+            .enumerate()
+            .map(|(index, start)| {
+                let notes: Vec<Note> = thirds
+                    .iter()
+                    .map(|third| degrees[(index + third) % degrees.len()])
+                    .collect();
+                arpeggiate(&notes, start, pattern, octaves)
+            })
+            .collect()
+    }
+}
+
+// the notes, listed from the bass up, played in the pattern across a number of octaves from the bottom pitch.
+// fails when the arpeggio would go past octave 127.
+fn arpeggiate(
+    notes: &[Note],
+    bottom: Pitch,
+    pattern: ArpeggioPattern,
+    octaves: usize,
+) -> Result<Vec<Pitch>, String> {
+    if octaves == 0 {
+        return Err("An arpeggio needs at least one octave".to_string());
+    }
+    let out_of_range = || format!("A {octaves} octave arpeggio from {bottom} goes past octave 127");
+    // every octave of the arpeggio ends on the bass at least an octave higher
+    if octaves > (i8::MAX as i16 - bottom.octave() as i16) as usize {
+        return Err(out_of_range());
+    }
+    // every tone goes just above the previous one, so chords spanning more than an octave keep rising
+    let length = notes.len();
+    let top = length * octaves;
+    let cycled: Vec<Note> = notes.iter().copied().cycle().take(top + length).collect();
+    let ladder = stack(bottom, &cycled).ok_or_else(out_of_range)?;
+    let up = ladder[..=top].to_vec();
+
+    let result = match pattern {
+        ArpeggioPattern::Up => up,
+        ArpeggioPattern::Down => up.into_iter().rev().collect(),
+        ArpeggioPattern::UpDown => {
+            let down: Vec<Pitch> = up[..top].iter().rev().copied().collect();
+            [up, down].concat()
+        }
+        ArpeggioPattern::Alternating => {
+            let mut result = vec![up[0]];
+            for &pitch in &up[1..] {
+                result.push(pitch);
+                result.push(up[0]);
+            }
+            result.pop();
+            result
+        }
+        ArpeggioPattern::InversionCycle => (0..=top)
+            .flat_map(|start| ladder[start..start + length].iter().copied())
+            .collect(),
+    };
+    Ok(result)
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod arpeggio_test {
+    use super::*;
+    use crate::chord::{ChordQuality, ChordSeventh};
+    use crate::scale::{ScaleDirection, ScaleKind};
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn print(pitches: &[Pitch]) -> String {
+        pitches
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn arpeggio(chord: &Chord, pattern: ArpeggioPattern, octaves: usize) -> String {
+        print(&chord.arpeggio(pattern, 4, octaves).unwrap())
+    }
+
+    #[test]
+    fn arpeggiates_up_and_down() {
+        let c_major = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(
+            arpeggio(&c_major, ArpeggioPattern::Up, 2),
+            "C4 E4 G4 C5 E5 G5 C6"
+        );
+        assert_eq!(arpeggio(&c_major, ArpeggioPattern::Down, 1), "C5 G4 E4 C4");
+        assert_eq!(
+            arpeggio(&c_major, ArpeggioPattern::UpDown, 1),
+            "C4 E4 G4 C5 G4 E4 C4"
+        );
+
+        let g7 = Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor);
+        assert_eq!(arpeggio(&g7, ArpeggioPattern::Up, 1), "G4 B4 D5 F5 G5");
+    }
+
+    #[test]
+    fn starts_inversions_and_slash_chords_from_the_bass() {
+        let c_major = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(
+            arpeggio(&c_major.inversion(1).unwrap(), ArpeggioPattern::Up, 1),
+            "E4 G4 C5 E5"
+        );
+        assert_eq!(
+            arpeggio(&c_major.with_bass(note("D")), ArpeggioPattern::Up, 1),
+            "D4 C5 E5 G5 D6"
+        );
+    }
+
+    #[test]
+    fn alternates_and_cycles_inversions() {
+        let a_minor = Chord::new(note("A"), ChordQuality::Minor);
+        assert_eq!(
+            arpeggio(&a_minor, ArpeggioPattern::Alternating, 1),
+            "A4 C5 A4 E5 A4 A5"
+        );
+        let c_major = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(
+            arpeggio(&c_major, ArpeggioPattern::InversionCycle, 1),
+            "C4 E4 G4 E4 G4 C5 G4 C5 E5 C5 E5 G5"
+        );
+        assert!(c_major.arpeggio(ArpeggioPattern::Up, 4, 0).is_err());
+    }
+
+    #[test]
+    fn arpeggiates_every_degree_of_a_scale() {
+        let c_major = Scale::new(note("C"), ScaleKind::Major, ScaleDirection::Ascending);
+        let triads = c_major.arpeggios(ArpeggioPattern::Up, false, 4, 1).unwrap();
+        assert_eq!(triads.len(), 7);
+        assert_eq!(print(&triads[0]), "C4 E4 G4 C5");
+        assert_eq!(print(&triads[6]), "B4 D5 F5 B5");
+
+        let sevenths = c_major
+            .arpeggios(ArpeggioPattern::Down, true, 4, 1)
+            .unwrap();
+        assert_eq!(print(&sevenths[4]), "G5 F5 D5 B4 G4");
+
+        let pentatonic = Scale::new(
+            note("C"),
+            ScaleKind::MajorPentatonic,
+            ScaleDirection::Ascending,
+        );
+        assert!(pentatonic
+            .arpeggios(ArpeggioPattern::Up, false, 4, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let c_major = Chord::new(note("C"), ChordQuality::Major);
+        assert!(c_major.arpeggio(ArpeggioPattern::Up, 4, 200).is_err());
+        assert!(c_major
+            .arpeggio(ArpeggioPattern::Up, 4, usize::MAX)
+            .is_err());
+        assert!(c_major.arpeggio(ArpeggioPattern::Up, 127, 1).is_err());
+        let top = c_major.arpeggio(ArpeggioPattern::Up, 126, 1).unwrap();
+        assert_eq!(top.last(), Some(&Pitch::try_from("C127").unwrap()));
+
+        let c_major_scale = Scale::new(note("C"), ScaleKind::Major, ScaleDirection::Ascending);
+        assert!(c_major_scale
+            .arpeggios(ArpeggioPattern::Up, true, 4, 200)
+            .is_err());
+        assert!(c_major_scale
+            .arpeggios(ArpeggioPattern::Up, false, 126, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn arpeggiates_degrees_without_a_chord() {
+        let hungarian_minor = Scale::new(
+            note("C"),
+            ScaleKind::HungarianMinor,
+            ScaleDirection::Ascending,
+        );
+        let triads = hungarian_minor
+            .arpeggios(ArpeggioPattern::Up, false, 4, 1)
+            .unwrap();
+        assert_eq!(print(&triads[1]), "D4 F#4 Ab4 D5");
+        assert_eq!(print(&triads[3]), "F#4 Ab4 C5 F#5");
+    }
+}
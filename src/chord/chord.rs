@@ -1,23 +1,52 @@
 #![allow(dead_code)]
 use crate::note::{Note, NotePitchInterval};
 
+use crate::fixed::FixedVec;
+
+use super::symbol::ChordSymbolFormatter;
+
 use std::fmt::{Display, Formatter};
 
+// a chord holds each interval above the root at most once, so there's room for all of them
+const MAX_INTERVALS: usize = 20;
+
+// building a chord doesn't allocate: the intervals live in a fixed size list,
+// and the name is written straight to the formatter when the chord is displayed
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Chord {
-    name: String,
     quality: ChordQuality,
     root: Note,
+    // the tones above the root, ordered by the degree they stack on (3, 5, 7, 9, 11, 13)
+    intervals: FixedVec<NotePitchInterval, MAX_INTERVALS>,
+    // the note sounding below the root for slash chords, e.g. the E in C/E
+    bass: Option<Note>,
 }
 
 // the chord quality a chord can be.
 // it describes the root triad in a chord.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ChordQuality {
     Major,
     Minor,
     Diminished,
     Augmented,
+    SuspendedSecond,
+    SuspendedFourth,
+}
+
+impl ChordQuality {
+    // the third (or the tone replacing it) and the fifth of the root triad
+    pub fn intervals(&self) -> [NotePitchInterval; 2] {
+        use NotePitchInterval::*;
+        match self {
+            ChordQuality::Major => [MajorThird, PerfectFifth],
+            ChordQuality::Minor => [MinorThird, PerfectFifth],
+            ChordQuality::Diminished => [MinorThird, DiminishedFifth],
+            ChordQuality::Augmented => [MajorThird, AugmentedFifth],
+            ChordQuality::SuspendedSecond => [MajorSecond, PerfectFifth],
+            ChordQuality::SuspendedFourth => [PerfectFourth, PerfectFifth],
+        }
+    }
 }
 
 impl Display for ChordQuality {
@@ -27,17 +56,36 @@ impl Display for ChordQuality {
             ChordQuality::Minor => write!(f, "Minor"),
             ChordQuality::Diminished => write!(f, "Diminished"),
             ChordQuality::Augmented => write!(f, "Augmented"),
+            ChordQuality::SuspendedSecond => write!(f, "Suspended Second"),
+            ChordQuality::SuspendedFourth => write!(f, "Suspended Fourth"),
         }
     }
 }
 
-// how to denote dominant / major seventh ?
-// how to denote suspended chords?
+// the seventh stacked on top of the root triad.
+// combined with the chord quality it distinguishes major, dominant, minor,
+// half-diminished and fully diminished seventh chords.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChordSeventh {
+    Major,
+    Minor,
+    Diminished,
+}
+
+impl ChordSeventh {
+    pub fn interval(&self) -> NotePitchInterval {
+        match self {
+            ChordSeventh::Major => NotePitchInterval::MajorSeventh,
+            ChordSeventh::Minor => NotePitchInterval::MinorSeventh,
+            ChordSeventh::Diminished => NotePitchInterval::DiminishedSeventh,
+        }
+    }
+}
 
 // describes the extensions of the chord.
 // each value includes the previous one.
 // e.g., Ninth includes Seventh and Triad.
-// TODO: more semantic name to describe this enum
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ChordExtensionKind {
     Triad,
     Seventh,
@@ -46,16 +94,116 @@ pub enum ChordExtensionKind {
     Thirteenth,
 }
 
+// a chromatic alteration of the fifth or of one of the upper extensions.
+// an alteration replaces the natural tone of the same degree, e.g. a flat ninth replaces the ninth.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChordAlteration {
+    FlatFifth,
+    SharpFifth,
+    FlatNinth,
+    SharpNinth,
+    SharpEleventh,
+    FlatThirteenth,
+}
+
+impl ChordAlteration {
+    pub fn interval(&self) -> NotePitchInterval {
+        use NotePitchInterval::*;
+        match self {
+            ChordAlteration::FlatFifth => DiminishedFifth,
+            ChordAlteration::SharpFifth => AugmentedFifth,
+            ChordAlteration::FlatNinth => MinorSecond,
+            ChordAlteration::SharpNinth => AugmentedSecond,
+            ChordAlteration::SharpEleventh => AugmentedFourth,
+            ChordAlteration::FlatThirteenth => MinorSixth,
+        }
+    }
+}
+
 impl Chord {
     pub fn new(root: Note, quality: ChordQuality) -> Self {
         Chord {
-            name: format!("{} {}", root.name(), quality),
             quality,
             root,
+            intervals: FixedVec::from_slice(&quality.intervals()).expect("a triad fits in a chord"),
+            bass: None,
+        }
+    }
+
+    // adds a seventh to the chord, replacing any seventh it already has
+    pub fn with_seventh(mut self, seventh: ChordSeventh) -> Self {
+        self.insert_replacing(seventh.interval());
+        self
+    }
+
+    // stacks natural extensions up to and including the given kind.
+    // if the chord has no seventh yet, a minor seventh is added (a diminished one for diminished triads),
+    // so Major + Ninth is a dominant ninth chord and Minor + Ninth is a minor ninth chord.
+    pub fn with_extension(mut self, extension: ChordExtensionKind) -> Self {
+        use ChordExtensionKind::*;
+        use NotePitchInterval::*;
+
+        if extension != Triad && self.seventh_interval().is_none() {
+            let seventh = match self.quality {
+                ChordQuality::Diminished if extension == Seventh => ChordSeventh::Diminished,
+                _ => ChordSeventh::Minor,
+            };
+            self.insert_replacing(seventh.interval());
         }
+        let tensions: &[NotePitchInterval] = match extension {
+            Triad | Seventh => &[],
+            Ninth => &[MajorSecond],
+            Eleventh => &[MajorSecond, PerfectFourth],
+            Thirteenth => &[MajorSecond, PerfectFourth, MajorSixth],
+        };
+        for &tension in tensions {
+            // keep any alteration already occupying the degree
+            if self.interval_at_degree(self.degree_of(tension)).is_none() {
+                self.insert(tension);
+            }
+        }
+        self
     }
-    pub fn name(&self) -> &str {
-        &self.name
+
+    // applies a chromatic alteration, replacing the natural tone of the same degree if present
+    pub fn with_alteration(mut self, alteration: ChordAlteration) -> Self {
+        use NotePitchInterval::*;
+        let interval = alteration.interval();
+        let natural = match self.degree_of(interval) {
+            5 => self.quality.intervals()[1],
+            9 => MajorSecond,
+            11 => PerfectFourth,
+            _ => MajorSixth,
+        };
+        self.intervals.retain(|&existing| existing != natural);
+        self.insert(interval);
+        self
+    }
+
+    // adds a tone without implying a seventh, e.g. the sixth of C6 or the ninth of Cadd9
+    pub fn with_added_tone(mut self, interval: NotePitchInterval) -> Self {
+        if interval != NotePitchInterval::PerfectUnison {
+            self.insert(interval);
+        }
+        self
+    }
+
+    // leaves out the fifth, as is common in voicings of extended chords
+    pub fn without_fifth(mut self) -> Self {
+        if let Some(fifth) = self.interval_at_degree(5) {
+            self.intervals.retain(|&interval| interval != fifth);
+        }
+        self
+    }
+
+    // sets the note sounding in the bass, e.g. C major over E is written C/E
+    pub fn with_bass(mut self, bass: Note) -> Self {
+        self.bass = if bass == self.root { None } else { Some(bass) };
+        self
+    }
+
+    pub fn name(&self) -> String {
+        self.to_string()
     }
     pub fn quality(&self) -> ChordQuality {
         self.quality
@@ -63,19 +211,266 @@ impl Chord {
     pub fn root(&self) -> Note {
         self.root
     }
-    pub fn third(&self) -> Note {
-        let interval = match self.quality {
-            ChordQuality::Major | ChordQuality::Augmented => NotePitchInterval::MajorThird,
-            ChordQuality::Minor | ChordQuality::Diminished => NotePitchInterval::MinorThird,
+    // the lowest sounding note. this is the root unless a different bass was given.
+    pub fn bass(&self) -> Note {
+        self.bass.unwrap_or(self.root)
+    }
+    // the intervals above the root of every tone in the chord, excluding the root itself
+    pub fn intervals(&self) -> &[NotePitchInterval] {
+        &self.intervals
+    }
+    // every chord tone from the bass up.
+    // in root position this is the root followed by the other tones in stacking order,
+    // inversions rotate that order so it starts on the bass (C/E is E G C),
+    // and a bass that isn't a chord tone is placed below the root position tones (C/D is D C E G).
+    pub fn notes(&self) -> Vec<Note> {
+        self.notes_iter().collect()
+    }
+    // the same notes as notes, without collecting them
+    pub fn notes_iter(&self) -> impl Iterator<Item = Note> + '_ {
+        let root_position = std::iter::once(self.root).chain(
+            self.intervals
+                .iter()
+                .map(|&interval| self.root.by_interval_ascending(interval)),
+        );
+        let count = self.intervals.len() + 1;
+        let (below, start) = match self.bass {
+            Some(bass) => match root_position.clone().position(|note| note == bass) {
+                Some(index) => (None, index),
+                None => (Some(bass), 0),
+            },
+            None => (None, 0),
         };
-        self.root.by_interval_ascending(interval)
+        below
+            .into_iter()
+            .chain(root_position.cycle().skip(start).take(count))
     }
-    pub fn fifth(&self) -> Note {
-        let interval = match self.quality {
-            ChordQuality::Major | ChordQuality::Minor => NotePitchInterval::PerfectFifth,
-            ChordQuality::Diminished => NotePitchInterval::DiminishedFifth,
-            ChordQuality::Augmented => NotePitchInterval::AugmentedFifth,
+    // puts the chord tone of the given inversion in the bass.
+    // 0 is root position, 1 puts the third in the bass, 2 the fifth and 3 the seventh.
+    pub fn inversion(&self, inversion: u8) -> Result<Chord, String> {
+        let interval = match inversion {
+            0 => return Ok(self.clone().with_bass(self.root)),
+            1 => self.interval_at_degree(3),
+            2 => self.interval_at_degree(5),
+            3 => self.interval_at_degree(7),
+            _ => {
+                return Err(format!(
+                    "{inversion} is not a valid inversion. Only inversions 0-3 are supported"
+                ))
+            }
         };
+        match interval {
+            Some(interval) => Ok(self
+                .clone()
+                .with_bass(self.root.by_interval_ascending(interval))),
+            None => Err(format!(
+                "{} has no chord tone to put in the bass for inversion {inversion}",
+                self.name()
+            )),
+        }
+    }
+    // which inversion the chord is in, judged by its bass.
+    // returns None for slash chords whose bass isn't the root, third, fifth or seventh, e.g. C/D.
+    pub fn inversion_number(&self) -> Option<u8> {
+        self.inversion_for_bass(&self.bass())
+    }
+    // which inversion the given bass note implies for this chord, if it's the root, third, fifth or seventh.
+    // enharmonic spellings of a chord tone count, so Db implies the first inversion of A major.
+    pub fn inversion_for_bass(&self, bass: &Note) -> Option<u8> {
+        if self.root.is_enharmonic(bass) {
+            return Some(0);
+        }
+        let interval = self.intervals.iter().copied().find(|&interval| {
+            self.root
+                .by_interval_ascending(interval)
+                .is_enharmonic(bass)
+        })?;
+        match self.degree_of(interval) {
+            3 => Some(1),
+            5 => Some(2),
+            7 => Some(3),
+            _ => None,
+        }
+    }
+    // the figured bass label of the inversion the chord is in.
+    // triads are figured "" (root position), "6" and "6/4", and seventh chords "7", "6/5", "4/3" and "4/2".
+    // returns None when the bass isn't the root, third, fifth or seventh.
+    pub fn figured_bass(&self) -> Option<&'static str> {
+        let has_seventh = self.seventh_interval().is_some();
+        let figures = match (self.inversion_number()?, has_seventh) {
+            (0, false) => "",
+            (1, false) => "6",
+            (2, false) => "6/4",
+            (0, true) => "7",
+            (1, true) => "6/5",
+            (2, true) => "4/3",
+            _ => "4/2",
+        };
+        Some(figures)
+    }
+    // the chord tones in root position, or None if one would need more than two accidentals
+    pub(crate) fn notes_checked(&self) -> Option<Vec<Note>> {
+        let mut result = vec![self.root];
+        for &interval in self.intervals.iter() {
+            result.push(self.root.checked_by_interval_ascending(interval)?);
+        }
+        Some(result)
+    }
+    // for suspended chords this is the suspended tone replacing the third
+    pub fn third(&self) -> Note {
+        self.root.by_interval_ascending(self.quality.intervals()[0])
+    }
+    pub fn fifth(&self) -> Note {
+        let interval = self
+            .interval_at_degree(5)
+            .unwrap_or(self.quality.intervals()[1]);
         self.root.by_interval_ascending(interval)
     }
+    pub fn seventh(&self) -> Option<Note> {
+        self.seventh_interval()
+            .map(|interval| self.root.by_interval_ascending(interval))
+    }
+    // whether the chord is a major triad with a minor seventh, e.g. G7, G9 or G7b9
+    pub fn is_dominant(&self) -> bool {
+        self.quality == ChordQuality::Major
+            && self.seventh_interval() == Some(NotePitchInterval::MinorSeventh)
+    }
+
+    // the interval of the chord tone stacked on the given degree (3, 5, 7, 9, 11 or 13), if any
+    pub(crate) fn interval_at_degree(&self, degree: u8) -> Option<NotePitchInterval> {
+        self.intervals
+            .iter()
+            .copied()
+            .find(|&interval| self.degree_of(interval) == degree)
+    }
+
+    pub(crate) fn seventh_interval(&self) -> Option<NotePitchInterval> {
+        self.interval_at_degree(7)
+    }
+
+    // maps an interval to the degree it occupies when the chord is stacked in thirds.
+    // seconds, fourths and sixths are upper extensions (9, 11, 13) unless they are the suspended tone.
+    pub(crate) fn degree_of(&self, interval: NotePitchInterval) -> u8 {
+        let number = interval.number();
+        if interval == self.quality.intervals()[0] {
+            return 3;
+        }
+        match number {
+            2 | 4 | 6 => number + 7,
+            _ => number,
+        }
+    }
+
+    // the same chord built on another root and bass, e.g. for transposition.
+    // None if a chord tone would need more than two accidentals.
+    pub(crate) fn moved_to(&self, root: Note, bass: Option<Note>) -> Option<Chord> {
+        let mut result = self.clone();
+        result.root = root;
+        result.bass = bass;
+        result.notes_checked()?;
+        Some(result)
+    }
+
+    fn insert(&mut self, interval: NotePitchInterval) {
+        if self.intervals.contains(&interval) {
+            return;
+        }
+        let mut intervals = self.intervals;
+        intervals
+            .push(interval)
+            .expect("a chord has room for every interval");
+        intervals.sort_by_key(|&interval| (self.degree_of(interval), interval.semitones()));
+        self.intervals = intervals;
+    }
+
+    fn insert_replacing(&mut self, interval: NotePitchInterval) {
+        let degree = self.degree_of(interval);
+        let mut intervals = self.intervals;
+        intervals.retain(|&existing| self.degree_of(existing) != degree);
+        self.intervals = intervals;
+        self.insert(interval);
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        ChordSymbolFormatter::default().write(self, f)
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod inversion_test {
+    use super::*;
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn names(notes: Vec<Note>) -> String {
+        notes
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn g7() -> Chord {
+        Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor)
+    }
+
+    #[test]
+    fn inversion_puts_chord_tone_in_bass() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(c.inversion(0).unwrap().bass(), note("C"));
+        assert_eq!(c.inversion(1).unwrap().bass(), note("E"));
+        assert_eq!(c.inversion(2).unwrap().bass(), note("G"));
+        assert_eq!(g7().inversion(3).unwrap().bass(), note("F"));
+        assert_eq!(c.inversion(2).unwrap().name(), "C/G");
+    }
+
+    #[test]
+    fn inversion_fails_without_chord_tone() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert!(c.inversion(3).is_err());
+        assert!(c.inversion(4).is_err());
+    }
+
+    #[test]
+    fn notes_follow_inversion() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(names(c.notes()), "C E G");
+        assert_eq!(names(c.inversion(1).unwrap().notes()), "E G C");
+        assert_eq!(names(c.inversion(2).unwrap().notes()), "G C E");
+        assert_eq!(names(g7().inversion(3).unwrap().notes()), "F G B D");
+        assert_eq!(names(c.with_bass(note("D")).notes()), "D C E G");
+    }
+
+    #[test]
+    fn detects_inversion_from_bass() {
+        let a = Chord::new(note("A"), ChordQuality::Major);
+        assert_eq!(a.inversion_for_bass(&note("A")), Some(0));
+        assert_eq!(a.inversion_for_bass(&note("C#")), Some(1));
+        assert_eq!(a.inversion_for_bass(&note("Db")), Some(1));
+        assert_eq!(a.inversion_for_bass(&note("E")), Some(2));
+        assert_eq!(a.inversion_for_bass(&note("B")), None);
+        assert_eq!(a.with_bass(note("B")).inversion_number(), None);
+        assert_eq!(g7().with_bass(note("F")).inversion_number(), Some(3));
+    }
+
+    #[test]
+    fn figured_bass_labels_inversions() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(c.figured_bass(), Some(""));
+        assert_eq!(c.inversion(1).unwrap().figured_bass(), Some("6"));
+        assert_eq!(c.inversion(2).unwrap().figured_bass(), Some("6/4"));
+        assert_eq!(g7().figured_bass(), Some("7"));
+        assert_eq!(g7().inversion(1).unwrap().figured_bass(), Some("6/5"));
+        assert_eq!(g7().inversion(2).unwrap().figured_bass(), Some("4/3"));
+        assert_eq!(g7().inversion(3).unwrap().figured_bass(), Some("4/2"));
+        assert_eq!(c.with_bass(note("D")).figured_bass(), None);
+    }
 }
@@ -0,0 +1,260 @@
+use crate::note::{Note, NotePitchInterval};
+
+use super::{Chord, ChordAlteration, ChordQuality, ChordSeventh};
+
+// one possible reading of a collection of notes as a chord.
+// a lower score means a simpler, more likely reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordCandidate {
+    chord: Chord,
+    score: u8,
+}
+
+impl ChordCandidate {
+    pub fn chord(&self) -> &Chord {
+        &self.chord
+    }
+    pub fn name(&self) -> String {
+        self.chord.name()
+    }
+    pub fn root(&self) -> Note {
+        self.chord.root()
+    }
+    pub fn quality(&self) -> ChordQuality {
+        self.chord.quality()
+    }
+    pub fn bass(&self) -> Note {
+        self.chord.bass()
+    }
+    // 0 for root position, 1 when the third is in the bass, 2 for the fifth and 3 for the seventh.
+    // None when the bass is an extension, e.g. the D of C9/D.
+    pub fn inversion(&self) -> Option<u8> {
+        self.chord.inversion_number()
+    }
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+}
+
+impl Chord {
+    // returns every way the notes can be read as a chord, best match first.
+    // the order of the notes doesn't matter except for the first one, which is taken as the bass.
+    // e.g. E G C reads as C/E, and C E G A reads as C6 before Am7/C.
+    pub fn identify(notes: &[Note]) -> Vec<ChordCandidate> {
+        let mut unique: Vec<Note> = vec![];
+        for &note in notes {
+            if !unique.iter().any(|existing| existing.is_enharmonic(&note)) {
+                unique.push(note);
+            }
+        }
+        let bass = match unique.first() {
+            Some(&bass) => bass,
+            None => return vec![],
+        };
+
+        let mut result: Vec<ChordCandidate> = unique
+            .iter()
+            .filter_map(|&root| candidate_from_root(root, bass, &unique))
+            .collect();
+        result.sort_by_key(|candidate| candidate.score);
+        result
+    }
+}
+
+// interprets every note relative to the given root as a chord degree.
+// the third and fifth are looked for first, then the seventh, and everything left is an extension.
+fn candidate_from_root(root: Note, bass: Note, notes: &[Note]) -> Option<ChordCandidate> {
+    use NotePitchInterval::*;
+
+    let mut remaining: Vec<u8> = notes
+        .iter()
+        .map(|note| root.semitones_to(note))
+        .filter(|&semitones| semitones != 0)
+        .collect();
+    let mut take = |semitones: u8| -> bool {
+        match remaining.iter().position(|&s| s == semitones) {
+            Some(index) => {
+                remaining.remove(index);
+                true
+            }
+            None => false,
+        }
+    };
+    let mut score = 0;
+
+    let third = if take(4) {
+        Some(MajorThird)
+    } else if take(3) {
+        Some(MinorThird)
+    } else {
+        None
+    };
+    let fifth = if take(7) {
+        Some(PerfectFifth)
+    } else if third == Some(MinorThird) && take(6) {
+        Some(DiminishedFifth)
+    } else if third == Some(MajorThird) && take(8) {
+        Some(AugmentedFifth)
+    } else {
+        None
+    };
+    let quality = match (third, fifth) {
+        (Some(MinorThird), Some(DiminishedFifth)) => ChordQuality::Diminished,
+        (Some(MajorThird), Some(AugmentedFifth)) => ChordQuality::Augmented,
+        (Some(MinorThird), _) => ChordQuality::Minor,
+        (Some(_), _) => ChordQuality::Major,
+        (None, _) if take(5) => {
+            score += 1;
+            ChordQuality::SuspendedFourth
+        }
+        (None, _) if take(2) => {
+            score += 1;
+            ChordQuality::SuspendedSecond
+        }
+        // a third or a suspension is needed to tell what kind of chord it is
+        (None, _) => return None,
+    };
+    let mut chord = Chord::new(root, quality);
+
+    let seventh = if take(11) {
+        Some(ChordSeventh::Major)
+    } else if take(10) {
+        Some(ChordSeventh::Minor)
+    } else if quality == ChordQuality::Diminished && take(9) {
+        Some(ChordSeventh::Diminished)
+    } else {
+        None
+    };
+    if let Some(seventh) = seventh {
+        chord = chord.with_seventh(seventh);
+    }
+
+    if fifth.is_none() {
+        if take(6) {
+            score += 3;
+            chord = chord.with_alteration(ChordAlteration::FlatFifth);
+        } else if take(8) {
+            score += 3;
+            chord = chord.with_alteration(ChordAlteration::SharpFifth);
+        } else {
+            score += 1;
+            chord = chord.without_fifth();
+        }
+    }
+
+    // the extensions are spelled as 9ths, 11ths and 13ths, so they're seconds, fourths and sixths above the root.
+    // anything else left over, like a second seventh or a major third next to a minor one, isn't a reading.
+    for semitones in remaining {
+        let (number, cost) = match semitones {
+            1 => (2, 2),
+            2 => (2, 1),
+            3 => (2, 2),
+            5 => (4, 1),
+            6 => (4, 2),
+            8 => (6, 2),
+            9 => (6, 1),
+            _ => return None,
+        };
+        let interval = NotePitchInterval::from_number_and_semitones(number, semitones)?;
+        score += cost;
+        chord = chord.with_added_tone(interval);
+    }
+
+    let tones = chord.notes_checked()?;
+    // prefer readings that keep the spelling the notes were given in
+    for note in notes {
+        if !tones.contains(note) {
+            score += 2;
+        }
+    }
+
+    let bass_tone = tones.iter().find(|tone| tone.is_enharmonic(&bass))?;
+    if *bass_tone != root {
+        score += 2;
+        chord = chord.with_bass(*bass_tone);
+    }
+
+    Some(ChordCandidate { chord, score })
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod identify_test {
+    use super::*;
+
+    fn notes(names: &str) -> Vec<Note> {
+        names
+            .split(' ')
+            .map(|name| Note::try_from(name).unwrap())
+            .collect()
+    }
+
+    fn names(candidates: &[ChordCandidate]) -> Vec<String> {
+        candidates
+            .iter()
+            .map(|candidate| candidate.name())
+            .collect()
+    }
+
+    #[test]
+    fn identifies_root_position_triads() {
+        assert_eq!(names(&Chord::identify(&notes("C E G")))[0], "C");
+        assert_eq!(names(&Chord::identify(&notes("A C E")))[0], "Am");
+        assert_eq!(names(&Chord::identify(&notes("B D F")))[0], "Bdim");
+        assert_eq!(names(&Chord::identify(&notes("C E G#")))[0], "Caug");
+        assert_eq!(names(&Chord::identify(&notes("C# E# G#")))[0], "C#");
+    }
+
+    #[test]
+    fn identifies_inversions_as_slash_chords() {
+        let candidates = Chord::identify(&notes("E G C"));
+        assert_eq!(candidates[0].name(), "C/E");
+        assert_eq!(candidates[0].inversion(), Some(1));
+        assert_eq!(candidates[0].root(), Note::try_from("C").unwrap());
+        assert_eq!(candidates[0].bass(), Note::try_from("E").unwrap());
+
+        let candidates = Chord::identify(&notes("G C E"));
+        assert_eq!(candidates[0].name(), "C/G");
+        assert_eq!(candidates[0].inversion(), Some(2));
+
+        let candidates = Chord::identify(&notes("F G B D"));
+        assert_eq!(candidates[0].name(), "G7/F");
+        assert_eq!(candidates[0].inversion(), Some(3));
+    }
+
+    #[test]
+    fn identifies_ambiguous_chords_in_order() {
+        let candidates = Chord::identify(&notes("C E G A"));
+        assert_eq!(names(&candidates)[0..2], ["C6", "Am7/C"]);
+        assert_eq!(candidates[1].inversion(), Some(1));
+    }
+
+    #[test]
+    fn identifies_seventh_chords_and_extensions() {
+        assert_eq!(names(&Chord::identify(&notes("G B D F")))[0], "G7");
+        assert_eq!(names(&Chord::identify(&notes("D F A C")))[0], "Dm7");
+        assert_eq!(names(&Chord::identify(&notes("B D F A")))[0], "Bm7b5");
+        assert_eq!(names(&Chord::identify(&notes("C E G B D")))[0], "Cmaj9");
+        assert_eq!(names(&Chord::identify(&notes("G B F Ab")))[0], "G7b9(no5)");
+        assert_eq!(names(&Chord::identify(&notes("C F G")))[0], "Csus4");
+    }
+
+    #[test]
+    fn rejects_leftover_tones_that_are_not_extensions() {
+        let candidates = Chord::identify(&notes("C E G Bb B"));
+        assert!(candidates
+            .iter()
+            .all(|candidate| !candidate.name().contains("13")));
+        assert!(candidates
+            .iter()
+            .all(|candidate| candidate.root() != Note::try_from("C").unwrap()));
+    }
+
+    #[test]
+    fn identifies_nothing_from_no_notes() {
+        assert!(Chord::identify(&[]).is_empty());
+    }
+}
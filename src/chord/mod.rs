@@ -1,2 +1,17 @@
 mod chord;
-pub use chord::{Chord, ChordQuality};
+pub use chord::{Chord, ChordAlteration, ChordExtensionKind, ChordQuality, ChordSeventh};
+
+mod symbol;
+pub use symbol::{AccidentalGlyphs, ChordSymbolFormatter, ChordSymbolStyle};
+
+mod identify;
+pub use identify::ChordCandidate;
+
+mod voicing;
+pub use voicing::{Voicing, VoicingKind};
+
+mod arpeggio;
+pub use arpeggio::ArpeggioPattern;
+
+mod voice_leading;
+pub use voice_leading::{VoiceLeading, VoiceLeadingOptions};
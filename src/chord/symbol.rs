@@ -0,0 +1,605 @@
+use std::fmt::{self, Write};
+
+use crate::fixed::FixedVec;
+use crate::note::{Note, NotePitchInterval};
+
+use super::{Chord, ChordQuality};
+
+// the notation conventions a chord symbol can be written in.
+// e.g. the C minor seventh chord is written C-7 (Jazz), Cm7 (LeadSheet),
+// C minor seventh (Classical) and C-7 (Berklee).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChordSymbolStyle {
+    Jazz,
+    LeadSheet,
+    Classical,
+    Berklee,
+}
+
+// the characters used to write sharps and flats, both on note names and on alterations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AccidentalGlyphs {
+    Ascii,
+    Unicode,
+}
+
+impl AccidentalGlyphs {
+    pub(crate) fn accidental(&self, offset: i8) -> &'static str {
+        match (self, offset) {
+            (AccidentalGlyphs::Ascii, -2) => "bb",
+            (AccidentalGlyphs::Ascii, -1) => "b",
+            (AccidentalGlyphs::Ascii, 1) => "#",
+            (AccidentalGlyphs::Ascii, 2) => "##",
+            (AccidentalGlyphs::Unicode, -2) => "\u{1D12B}",
+            (AccidentalGlyphs::Unicode, -1) => "\u{266D}",
+            (AccidentalGlyphs::Unicode, 1) => "\u{266F}",
+            (AccidentalGlyphs::Unicode, 2) => "\u{1D12A}",
+            _ => "",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ChordSymbolFormatter {
+    style: ChordSymbolStyle,
+    glyphs: AccidentalGlyphs,
+}
+
+impl Default for ChordSymbolFormatter {
+    fn default() -> Self {
+        ChordSymbolFormatter::new(ChordSymbolStyle::LeadSheet, AccidentalGlyphs::Ascii)
+    }
+}
+
+// a tone of the chord written relative to its natural degree, e.g. b9 or #11
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Tension {
+    offset: i8,
+    degree: u8,
+}
+
+impl ChordSymbolFormatter {
+    pub fn new(style: ChordSymbolStyle, glyphs: AccidentalGlyphs) -> Self {
+        Self { style, glyphs }
+    }
+
+    pub fn style(&self) -> ChordSymbolStyle {
+        self.style
+    }
+
+    pub fn glyphs(&self) -> AccidentalGlyphs {
+        self.glyphs
+    }
+
+    pub fn format(&self, chord: &Chord) -> String {
+        let mut result = String::new();
+        self.write(chord, &mut result)
+            .expect("writing to a string doesn't fail");
+        result
+    }
+
+    // writes the chord symbol straight to the output, so formatting a chord doesn't allocate
+    pub fn write(&self, chord: &Chord, out: &mut impl Write) -> fmt::Result {
+        let out = &mut Output {
+            inner: out,
+            last: None,
+        };
+        let seventh = chord.seventh_interval();
+        let mut alterations: Tensions = FixedVec::new(NO_TENSION);
+        let mut added: Tensions = FixedVec::new(NO_TENSION);
+        let mut highest_natural = 7;
+
+        let natural_fifth = chord.quality().intervals()[1];
+        match chord.interval_at_degree(5) {
+            Some(fifth) if fifth != natural_fifth => add(&mut alterations, tension(fifth, 5)),
+            _ => {}
+        }
+        for &interval in chord.intervals() {
+            let degree = chord.degree_of(interval);
+            if degree < 9 {
+                continue;
+            }
+            let tension = tension(interval, degree);
+            match seventh {
+                Some(NotePitchInterval::DiminishedSeventh) | None => {
+                    if tension.offset == 0 {
+                        add(&mut added, tension);
+                    } else {
+                        add(&mut alterations, tension);
+                    }
+                }
+                Some(_) if tension.offset == 0 => highest_natural = degree,
+                Some(_) => add(&mut alterations, tension),
+            }
+        }
+
+        self.write_note(out, chord.root())?;
+        match self.style {
+            ChordSymbolStyle::Classical => {
+                out.write_char(' ')?;
+                self.write_classical_body(out, chord, seventh, highest_natural, &added)?;
+                let without_fifth = chord.interval_at_degree(5).is_none();
+                if !alterations.is_empty() || without_fifth {
+                    out.write_str(" with ")?;
+                    for (index, &tension) in alterations.iter().enumerate() {
+                        if index > 0 {
+                            out.write_str(", ")?;
+                        }
+                        self.write_tension_words(out, tension, seventh.is_some())?;
+                    }
+                    if without_fifth {
+                        if !alterations.is_empty() {
+                            out.write_str(", ")?;
+                        }
+                        out.write_str("without fifth")?;
+                    }
+                }
+                if chord.bass() != chord.root() {
+                    out.write_str(" over ")?;
+                    self.write_note(out, chord.bass())?;
+                }
+            }
+            _ => {
+                let body_alteration =
+                    self.write_symbol_body(out, chord, seventh, highest_natural, &mut added)?;
+                let mut details: Tensions = FixedVec::new(NO_TENSION);
+                for &tension in body_alteration.iter().chain(alterations.iter()) {
+                    add(&mut details, tension);
+                }
+                self.write_details(out, &details, &added, seventh.is_some())?;
+                if chord.interval_at_degree(5).is_none() {
+                    out.write_str("(no5)")?;
+                }
+                if chord.bass() != chord.root() {
+                    out.write_char('/')?;
+                    self.write_note(out, chord.bass())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_note(&self, out: &mut Output, note: Note) -> fmt::Result {
+        let offset = i8::from(note.pitch_variant());
+        write!(out, "{}{}", note.name(), self.glyphs.accidental(offset))
+    }
+
+    // the quality, seventh and highest extension of a chord symbol, e.g. "m7", "maj9" or "6/9".
+    // returns the alteration that is part of how the style spells the quality, if any, e.g. the b5 of Berklee's -7(b5).
+    fn write_symbol_body(
+        &self,
+        out: &mut Output,
+        chord: &Chord,
+        seventh: Option<NotePitchInterval>,
+        highest_natural: u8,
+        added: &mut Tensions,
+    ) -> Result<Option<Tension>, fmt::Error> {
+        use ChordQuality::*;
+        use ChordSymbolStyle::*;
+        use NotePitchInterval::*;
+
+        let flat_five = Tension {
+            offset: -1,
+            degree: 5,
+        };
+        let sharp_five = Tension {
+            offset: 1,
+            degree: 5,
+        };
+        let n = highest_natural;
+        let quality = chord.quality();
+
+        match seventh {
+            None => {
+                let triad = match (self.style, quality) {
+                    (_, Major) => "",
+                    (LeadSheet, Minor) => "m",
+                    (_, Minor) => "-",
+                    (LeadSheet, Diminished) => "dim",
+                    (Jazz, Diminished) => "\u{00B0}",
+                    (_, Diminished) => "o",
+                    (LeadSheet, Augmented) => "aug",
+                    (_, Augmented) => "+",
+                    (_, SuspendedSecond) => "sus2",
+                    (_, SuspendedFourth) => "sus4",
+                };
+                out.write_str(triad)?;
+                let has = |added: &Tensions, degree| added.iter().any(|t| t.degree == degree);
+                if has(added, 13) && matches!(quality, Major | Minor) {
+                    added.retain(|tension| tension.degree != 13);
+                    if has(added, 9) {
+                        added.retain(|tension| tension.degree != 9);
+                        out.write_str("6/9")?;
+                    } else {
+                        out.write_str("6")?;
+                    }
+                }
+            }
+            Some(DiminishedSeventh) if quality == Diminished => match self.style {
+                LeadSheet => out.write_str("dim7")?,
+                Jazz => out.write_str("\u{00B0}7")?,
+                _ => out.write_str("o7")?,
+            },
+            Some(MajorSeventh) => match (self.style, quality) {
+                (Jazz, Major) => write!(out, "\u{2206}{n}")?,
+                (_, Major) => write!(out, "maj{n}")?,
+                (LeadSheet, Minor) => write!(out, "m(maj{n})")?,
+                (Jazz, Minor) => write!(out, "-\u{2206}{n}")?,
+                (_, Minor) => write!(out, "-(maj{n})")?,
+                (LeadSheet, Augmented) => {
+                    write!(out, "maj{n}")?;
+                    return Ok(Some(sharp_five));
+                }
+                (Jazz, Augmented) => write!(out, "+\u{2206}{n}")?,
+                (_, Augmented) => write!(out, "+(maj{n})")?,
+                (_, Diminished) => {
+                    write!(out, "maj{n}")?;
+                    return Ok(Some(flat_five));
+                }
+                (_, SuspendedSecond) => write!(out, "maj{n}sus2")?,
+                (_, SuspendedFourth) => write!(out, "maj{n}sus4")?,
+            },
+            Some(_) => match (self.style, quality) {
+                (_, Major) => write!(out, "{n}")?,
+                (LeadSheet, Minor) => write!(out, "m{n}")?,
+                (_, Minor) => write!(out, "-{n}")?,
+                (LeadSheet, Diminished) => {
+                    write!(out, "m{n}")?;
+                    return Ok(Some(flat_five));
+                }
+                (Jazz, Diminished) => write!(out, "\u{00F8}{n}")?,
+                (_, Diminished) => {
+                    write!(out, "-{n}")?;
+                    return Ok(Some(flat_five));
+                }
+                (LeadSheet, Augmented) => {
+                    write!(out, "{n}")?;
+                    return Ok(Some(sharp_five));
+                }
+                (_, Augmented) => write!(out, "+{n}")?,
+                (_, SuspendedSecond) => write!(out, "{n}sus2")?,
+                (_, SuspendedFourth) => write!(out, "{n}sus4")?,
+            },
+        }
+        Ok(None)
+    }
+
+    fn write_classical_body(
+        &self,
+        out: &mut Output,
+        chord: &Chord,
+        seventh: Option<NotePitchInterval>,
+        highest_natural: u8,
+        added: &[Tension],
+    ) -> fmt::Result {
+        use ChordQuality::*;
+        use NotePitchInterval::*;
+
+        let quality = chord.quality();
+        match seventh {
+            None => {
+                let has_sixth = added.iter().any(|tension| tension.degree == 13);
+                let name = match quality {
+                    Major if has_sixth => "major sixth",
+                    Minor if has_sixth => "minor sixth",
+                    Major => "major",
+                    Minor => "minor",
+                    Diminished => "diminished",
+                    Augmented => "augmented",
+                    SuspendedSecond => "suspended second",
+                    SuspendedFourth => "suspended fourth",
+                };
+                out.write_str(name)?;
+            }
+            Some(interval) => {
+                let kind = match (quality, interval) {
+                    (Diminished, DiminishedSeventh) => "diminished",
+                    (Diminished, _) => "half-diminished",
+                    (Major, MajorSeventh) => "major",
+                    (Minor, MajorSeventh) => "minor-major",
+                    (Minor, _) => "minor",
+                    (Augmented, MajorSeventh) => "augmented major",
+                    (Augmented, _) => "augmented",
+                    (_, MajorSeventh) => "major",
+                    _ => "dominant",
+                };
+                let extension = match highest_natural {
+                    9 => "ninth",
+                    11 => "eleventh",
+                    13 => "thirteenth",
+                    _ => "seventh",
+                };
+                let suspension = match quality {
+                    SuspendedSecond => " suspended second",
+                    SuspendedFourth => " suspended fourth",
+                    _ => "",
+                };
+                write!(out, "{kind} {extension}{suspension}")?;
+            }
+        }
+        let added = added.iter().filter(|tension| {
+            seventh.is_some() || tension.degree != 13 || !matches!(quality, Major | Minor)
+        });
+        for (index, &tension) in added.enumerate() {
+            out.write_str(if index == 0 { " with " } else { ", " })?;
+            out.write_str("added ")?;
+            self.write_tension_words(out, tension, false)?;
+        }
+        Ok(())
+    }
+
+    fn write_tension_symbol(
+        &self,
+        out: &mut Output,
+        tension: Tension,
+        has_seventh: bool,
+    ) -> fmt::Result {
+        write!(
+            out,
+            "{}{}",
+            self.glyphs.accidental(tension.offset),
+            displayed_degree(tension.degree, has_seventh)
+        )
+    }
+
+    fn write_tension_words(
+        &self,
+        out: &mut Output,
+        tension: Tension,
+        has_seventh: bool,
+    ) -> fmt::Result {
+        let accidental = match tension.offset {
+            -2 => "double flat ",
+            -1 => "flat ",
+            1 => "sharp ",
+            2 => "double sharp ",
+            _ => "",
+        };
+        let degree = match displayed_degree(tension.degree, has_seventh) {
+            5 => "fifth",
+            6 => "sixth",
+            9 => "ninth",
+            11 => "eleventh",
+            _ => "thirteenth",
+        };
+        write!(out, "{accidental}{degree}")
+    }
+
+    // alterations follow a number directly in lead sheet and jazz symbols (C7b9),
+    // but are parenthesized when they would otherwise touch a letter (C(b5)) and always in Berklee style.
+    // added tones come after the alterations, e.g. Cm(add9).
+    fn write_details(
+        &self,
+        out: &mut Output,
+        alterations: &[Tension],
+        added: &[Tension],
+        has_seventh: bool,
+    ) -> fmt::Result {
+        if alterations.is_empty() && added.is_empty() {
+            return Ok(());
+        }
+        let ends_with_number = out.last.is_some_and(|c| c.is_ascii_digit());
+        let berklee = self.style == ChordSymbolStyle::Berklee;
+        let parenthesized = berklee || !ends_with_number;
+        if parenthesized {
+            out.write_char('(')?;
+        }
+        let details = alterations
+            .iter()
+            .map(|&tension| ("", tension, has_seventh))
+            .chain(added.iter().map(|&tension| ("add", tension, false)));
+        for (index, (prefix, tension, has_seventh)) in details.enumerate() {
+            if berklee && index > 0 {
+                out.write_str(", ")?;
+            }
+            out.write_str(prefix)?;
+            self.write_tension_symbol(out, tension, has_seventh)?;
+        }
+        if parenthesized {
+            out.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+// passes the symbol on to the output while remembering its last character,
+// so the details can tell whether they follow a number
+struct Output<'a> {
+    inner: &'a mut dyn Write,
+    last: Option<char>,
+}
+
+impl Write for Output<'_> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        if let Some(last) = text.chars().last() {
+            self.last = Some(last);
+        }
+        self.inner.write_str(text)
+    }
+}
+
+// room for an altered fifth, all nine seconds, fourths and sixths a chord can stack as
+// ninths, elevenths and thirteenths, and the alteration a style spells the quality with
+type Tensions = FixedVec<Tension, 12>;
+
+const NO_TENSION: Tension = Tension {
+    offset: 0,
+    degree: 0,
+};
+
+fn add(tensions: &mut Tensions, tension: Tension) {
+    tensions
+        .push(tension)
+        .expect("a chord has room for all of its tensions");
+}
+
+fn tension(interval: NotePitchInterval, degree: u8) -> Tension {
+    use NotePitchInterval::*;
+    let natural = match interval.number() {
+        2 => MajorSecond,
+        4 => PerfectFourth,
+        5 => PerfectFifth,
+        _ => MajorSixth,
+    };
+    Tension {
+        offset: interval.semitones() as i8 - natural.semitones() as i8,
+        degree,
+    }
+}
+
+// thirteenths are called sixths when there is no seventh below them
+fn displayed_degree(degree: u8, has_seventh: bool) -> u8 {
+    if degree == 13 && !has_seventh {
+        6
+    } else {
+        degree
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod chord_symbol_test {
+    use super::*;
+    use crate::chord::{ChordAlteration, ChordExtensionKind, ChordSeventh};
+    use AccidentalGlyphs::*;
+    use ChordQuality::*;
+    use ChordSymbolStyle::*;
+
+    fn chord(root: &str, quality: ChordQuality) -> Chord {
+        Chord::new(Note::try_from(root).unwrap(), quality)
+    }
+
+    fn test_case(chord: &Chord, style: ChordSymbolStyle, expected: &str) {
+        let actual = ChordSymbolFormatter::new(style, Ascii).format(chord);
+        assert_eq!(actual, expected.to_string());
+    }
+
+    #[test]
+    fn formats_triads() {
+        test_case(&chord("C", Major), LeadSheet, "C");
+        test_case(&chord("C#", Minor), LeadSheet, "C#m");
+        test_case(&chord("Bb", Diminished), LeadSheet, "Bbdim");
+        test_case(&chord("Eb", Augmented), LeadSheet, "Ebaug");
+        test_case(&chord("C", Minor), Jazz, "C-");
+        test_case(&chord("C", Diminished), Jazz, "C\u{00B0}");
+        test_case(&chord("C", Augmented), Berklee, "C+");
+        test_case(&chord("D", SuspendedFourth), LeadSheet, "Dsus4");
+        test_case(&chord("C#", Minor), Classical, "C# minor");
+    }
+
+    #[test]
+    fn formats_seventh_chords() {
+        let major_seventh = chord("C", Major).with_seventh(ChordSeventh::Major);
+        test_case(&major_seventh, Jazz, "C\u{2206}7");
+        test_case(&major_seventh, LeadSheet, "Cmaj7");
+        test_case(&major_seventh, Classical, "C major seventh");
+        test_case(&major_seventh, Berklee, "Cmaj7");
+
+        let minor_seventh = chord("C", Minor).with_seventh(ChordSeventh::Minor);
+        test_case(&minor_seventh, Jazz, "C-7");
+        test_case(&minor_seventh, LeadSheet, "Cm7");
+        test_case(&minor_seventh, Classical, "C minor seventh");
+        test_case(&minor_seventh, Berklee, "C-7");
+
+        let half_diminished = chord("B", Diminished).with_seventh(ChordSeventh::Minor);
+        test_case(&half_diminished, Jazz, "B\u{00F8}7");
+        test_case(&half_diminished, LeadSheet, "Bm7b5");
+        test_case(&half_diminished, Classical, "B half-diminished seventh");
+        test_case(&half_diminished, Berklee, "B-7(b5)");
+
+        let diminished = chord("B", Diminished).with_seventh(ChordSeventh::Diminished);
+        test_case(&diminished, LeadSheet, "Bdim7");
+        test_case(&diminished, Berklee, "Bo7");
+
+        let dominant = chord("G", Major).with_seventh(ChordSeventh::Minor);
+        test_case(&dominant, LeadSheet, "G7");
+        test_case(&dominant, Classical, "G dominant seventh");
+
+        let minor_major = chord("C", Minor).with_seventh(ChordSeventh::Major);
+        test_case(&minor_major, LeadSheet, "Cm(maj7)");
+        test_case(&minor_major, Berklee, "C-(maj7)");
+
+        let augmented = chord("C", Augmented).with_seventh(ChordSeventh::Minor);
+        test_case(&augmented, LeadSheet, "C7#5");
+        test_case(&augmented, Jazz, "C+7");
+    }
+
+    #[test]
+    fn formats_extensions_and_alterations() {
+        let dominant_ninth = chord("G", Major).with_extension(ChordExtensionKind::Ninth);
+        test_case(&dominant_ninth, LeadSheet, "G9");
+        test_case(&dominant_ninth, Classical, "G dominant ninth");
+
+        let major_ninth = chord("C", Major)
+            .with_seventh(ChordSeventh::Major)
+            .with_extension(ChordExtensionKind::Ninth);
+        test_case(&major_ninth, Jazz, "C\u{2206}9");
+
+        let minor_eleventh = chord("D", Minor).with_extension(ChordExtensionKind::Eleventh);
+        test_case(&minor_eleventh, LeadSheet, "Dm11");
+
+        let altered = chord("G", Major)
+            .with_extension(ChordExtensionKind::Thirteenth)
+            .with_alteration(ChordAlteration::FlatNinth)
+            .with_alteration(ChordAlteration::SharpEleventh);
+        test_case(&altered, LeadSheet, "G13b9#11");
+        test_case(&altered, Berklee, "G13(b9, #11)");
+        test_case(
+            &altered,
+            Classical,
+            "G dominant thirteenth with flat ninth, sharp eleventh",
+        );
+
+        let flat_five = chord("C", Major).with_alteration(ChordAlteration::FlatFifth);
+        test_case(&flat_five, LeadSheet, "C(b5)");
+    }
+
+    #[test]
+    fn formats_added_tones() {
+        use NotePitchInterval::*;
+        let sixth = chord("C", Major).with_added_tone(MajorSixth);
+        test_case(&sixth, LeadSheet, "C6");
+        test_case(&sixth, Classical, "C major sixth");
+
+        let minor_six_nine = chord("C", Minor)
+            .with_added_tone(MajorSixth)
+            .with_added_tone(MajorSecond);
+        test_case(&minor_six_nine, LeadSheet, "Cm6/9");
+
+        let add_nine = chord("C", Major).with_added_tone(MajorSecond);
+        test_case(&add_nine, LeadSheet, "C(add9)");
+        test_case(&add_nine, Classical, "C major with added ninth");
+    }
+
+    #[test]
+    fn formats_slash_bass() {
+        let c = chord("C", Major);
+        let first_inversion = c.clone().with_bass(c.third());
+        test_case(&first_inversion, LeadSheet, "C/E");
+        test_case(&first_inversion, Classical, "C major over E");
+
+        let minor_seventh = chord("F#", Minor)
+            .with_seventh(ChordSeventh::Minor)
+            .with_bass(Note::try_from("E").unwrap());
+        test_case(&minor_seventh, Berklee, "F#-7/E");
+    }
+
+    #[test]
+    fn formats_accidentals_with_unicode_glyphs() {
+        let formatter = ChordSymbolFormatter::new(LeadSheet, Unicode);
+        let chord = chord("Bb", Major)
+            .with_seventh(ChordSeventh::Minor)
+            .with_alteration(ChordAlteration::SharpNinth)
+            .with_bass(Note::try_from("F#").unwrap());
+        assert_eq!(formatter.format(&chord), "B\u{266D}7\u{266F}9/F\u{266F}");
+    }
+
+    #[test]
+    fn chord_name_keeps_root_accidental() {
+        assert_eq!(chord("C#", Major).name(), "C#");
+        assert_eq!(chord("Db", Minor).name(), "Dbm");
+    }
+}
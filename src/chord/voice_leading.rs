@@ -0,0 +1,589 @@
+use crate::note::{Note, NotePitchInterval, Pitch};
+
+use super::{Chord, ChordQuality};
+
+use std::collections::HashSet;
+
+// the cost of breaking one of the enabled voice leading rules, counted in semitones of movement
+const RULE_PENALTY: u32 = 12;
+
+// the constraints voices are led under.
+// the lowest voice always sounds the bass of each chord, and neighbouring upper voices stay within an octave.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VoiceLeadingOptions {
+    voices: usize,
+    low: Pitch,
+    high: Pitch,
+    keep_common_tones: bool,
+    avoid_voice_crossing: bool,
+    resolve_tendency_tones: bool,
+}
+
+impl VoiceLeadingOptions {
+    // leads the given number of voices between low and high inclusive.
+    // voice crossing is avoided by default, and the other rules are off.
+    pub fn new(voices: usize, low: Pitch, high: Pitch) -> Self {
+        Self {
+            voices,
+            low,
+            high,
+            keep_common_tones: false,
+            avoid_voice_crossing: true,
+            resolve_tendency_tones: false,
+        }
+    }
+
+    // keep a voice on the same pitch when its note is also in the next chord
+    pub fn with_common_tones(mut self, keep_common_tones: bool) -> Self {
+        self.keep_common_tones = keep_common_tones;
+        self
+    }
+
+    pub fn with_voice_crossing(mut self, allow_voice_crossing: bool) -> Self {
+        self.avoid_voice_crossing = !allow_voice_crossing;
+        self
+    }
+
+    // move leading tones up by a semitone and sevenths down by step when the next chord has the note of resolution
+    pub fn with_tendency_tones(mut self, resolve_tendency_tones: bool) -> Self {
+        self.resolve_tendency_tones = resolve_tendency_tones;
+        self
+    }
+
+    pub fn voices(&self) -> usize {
+        self.voices
+    }
+}
+
+// the voicing of every chord in a progression.
+// each voicing lists its pitches by voice, starting from the bass, so voice n is found at index n of every voicing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceLeading {
+    voicings: Vec<Vec<Pitch>>,
+    total_movement: u32,
+}
+
+// what the voices of one chord should do when moving to the next one
+struct Transition {
+    next_notes: HashSet<u8>,
+    leading_tone: Option<Note>,
+    seventh: Option<Note>,
+}
+
+// a transition between two voicings: its cost and where each voice of the first voicing moves to in the second
+struct Motion {
+    cost: u32,
+    movement: u32,
+    destinations: Vec<usize>,
+}
+
+impl VoiceLeading {
+    // finds the voicings of the chords with the least total movement that respect the options.
+    // returns an error when a chord can't be voiced with the given number of voices within the range.
+    pub fn new(chords: &[Chord], options: VoiceLeadingOptions) -> Result<Self, String> {
+        if options.voices < 2 {
+            return Err(format!(
+                "{} voices are too few to lead. At least 2 are needed",
+                options.voices
+            ));
+        }
+        let candidates: Vec<Vec<Vec<Pitch>>> = chords
+            .iter()
+            .map(|chord| candidate_voicings(chord, &options))
+            .collect();
+        if let Some(index) = candidates.iter().position(|voicings| voicings.is_empty()) {
+            return Err(format!(
+                "{} can't be voiced with {} voices between {} and {}",
+                chords[index].name(),
+                options.voices,
+                options.low,
+                options.high
+            ));
+        }
+        if chords.is_empty() {
+            return Ok(VoiceLeading {
+                voicings: vec![],
+                total_movement: 0,
+            });
+        }
+
+        // best[i][s] is the cheapest way to reach voicing s of chord i, with the voicing it came from
+        let mut best: Vec<Vec<(u32, usize)>> = vec![vec![(0, 0); candidates[0].len()]];
+        let transitions: Vec<Transition> = chords
+            .windows(2)
+            .map(|pair| Transition::new(&pair[0], &pair[1]))
+            .collect();
+        for i in 1..chords.len() {
+            let row = candidates[i]
+                .iter()
+                .map(|next| {
+                    candidates[i - 1]
+                        .iter()
+                        .enumerate()
+                        .map(|(index, previous)| {
+                            let motion = transitions[i - 1].motion(previous, next, &options);
+                            (best[i - 1][index].0 + motion.cost, index)
+                        })
+                        .min()
+                        .unwrap()
+                })
+                .collect();
+            best.push(row);
+        }
+
+        // walk back from the cheapest final voicing
+        let last = chords.len() - 1;
+        let mut path = vec![0; chords.len()];
+        path[last] = (0..candidates[last].len())
+            .min_by_key(|&index| best[last][index])
+            .unwrap();
+        for i in (1..chords.len()).rev() {
+            path[i - 1] = best[i][path[i]].1;
+        }
+
+        // follow each voice through the chosen voicings, which may swap places when crossing is allowed
+        let mut order: Vec<usize> = (0..options.voices).collect();
+        let mut voicings = vec![candidates[0][path[0]].clone()];
+        let mut total_movement = 0;
+        for i in 1..chords.len() {
+            let previous = &candidates[i - 1][path[i - 1]];
+            let next = &candidates[i][path[i]];
+            let motion = transitions[i - 1].motion(previous, next, &options);
+            total_movement += motion.movement;
+            order = order
+                .iter()
+                .map(|&position| motion.destinations[position])
+                .collect();
+            voicings.push(order.iter().map(|&position| next[position]).collect());
+        }
+
+        Ok(VoiceLeading {
+            voicings,
+            total_movement,
+        })
+    }
+
+    // the pitches of every chord, listed by voice starting from the bass
+    pub fn voicings(&self) -> &[Vec<Pitch>] {
+        &self.voicings
+    }
+
+    // the melodic line of a single voice, where 0 is the bass
+    pub fn voice(&self, voice: usize) -> Vec<Pitch> {
+        self.voicings.iter().map(|voicing| voicing[voice]).collect()
+    }
+
+    // the sum of the distances in semitones every voice moves
+    pub fn total_movement(&self) -> u32 {
+        self.total_movement
+    }
+}
+
+// every voicing of the chord from the bass up that fits the options.
+// the chord tones that must be present are picked in order of importance: the third, seventh, extensions,
+// fifth and root, so the fifth is the first tone left out when there are fewer voices than tones.
+fn candidate_voicings(chord: &Chord, options: &VoiceLeadingOptions) -> Vec<Vec<Pitch>> {
+    let bass = chord.bass();
+    let mut tones: Vec<Note> = vec![];
+    for degree in [3, 7, 9, 11, 13, 5] {
+        for &interval in chord.intervals() {
+            if chord.degree_of(interval) == degree {
+                match chord.root().checked_by_interval_ascending(interval) {
+                    Some(tone) => tones.push(tone),
+                    None => return vec![],
+                }
+            }
+        }
+    }
+    tones.push(chord.root());
+    tones.retain(|note| !note.is_enharmonic(&bass));
+    let required: Vec<Note> = tones.iter().copied().take(options.voices - 1).collect();
+    let mut available = tones.clone();
+    available.push(bass);
+
+    let in_range = |pitch: &Pitch| {
+        pitch.midi_number() >= options.low.midi_number()
+            && pitch.midi_number() <= options.high.midi_number()
+    };
+    let placements = |note: Note| -> Vec<Pitch> {
+        (options.low.octave().saturating_sub(1)..=options.high.octave().saturating_add(1))
+            .map(|octave| Pitch::new(note, octave))
+            .filter(in_range)
+            .collect()
+    };
+
+    let mut result = vec![];
+    for bass_pitch in placements(bass) {
+        let mut upper: Vec<Pitch> = available
+            .iter()
+            .flat_map(|&note| placements(note))
+            .filter(|pitch| pitch.midi_number() > bass_pitch.midi_number())
+            .collect();
+        upper.sort();
+        let mut current = vec![bass_pitch];
+        collect_upper_voices(
+            &upper,
+            0,
+            options.voices,
+            &required,
+            &mut current,
+            &mut result,
+        );
+    }
+    result
+}
+
+// picks the upper voices from the lowest up, keeping neighbours within an octave of each other
+fn collect_upper_voices(
+    upper: &[Pitch],
+    start: usize,
+    voices: usize,
+    required: &[Note],
+    current: &mut Vec<Pitch>,
+    result: &mut Vec<Vec<Pitch>>,
+) {
+    if current.len() == voices {
+        let covered = required
+            .iter()
+            .all(|note| current.iter().any(|pitch| pitch.note() == *note));
+        if covered {
+            result.push(current.clone());
+        }
+        return;
+    }
+    for index in start..upper.len() {
+        let pitch = upper[index];
+        let previous = current[current.len() - 1];
+        if current.len() > 1 && pitch.midi_number() - previous.midi_number() > 12 {
+            break;
+        }
+        if pitch.midi_number() == previous.midi_number() {
+            continue;
+        }
+        current.push(pitch);
+        collect_upper_voices(upper, index + 1, voices, required, current, result);
+        current.pop();
+    }
+}
+
+impl Transition {
+    fn new(previous: &Chord, next: &Chord) -> Self {
+        let leading_tone = match (previous.quality(), previous.seventh_interval()) {
+            (ChordQuality::Major, Some(NotePitchInterval::MinorSeventh)) => Some(previous.third()),
+            (ChordQuality::Diminished, _) => Some(previous.root()),
+            _ => None,
+        };
+        Transition {
+            next_notes: next.notes().iter().map(|note| note.pitch_class()).collect(),
+            leading_tone,
+            seventh: previous.seventh(),
+        }
+    }
+
+    // the cost and movement of moving one voice from one pitch to another
+    fn voice_cost(&self, from: Pitch, to: Pitch, options: &VoiceLeadingOptions) -> (u32, u32) {
+        let step = to.midi_number() - from.midi_number();
+        let movement = step.unsigned_abs() as u32;
+        let class = from.note().pitch_class();
+        let resolves_to = |semitones: i16| {
+            let target = (class as i16 + semitones).rem_euclid(12) as u8;
+            self.next_notes.contains(&target)
+        };
+        let is = |note: Option<Note>| note.is_some_and(|note| note.is_enharmonic(&from.note()));
+
+        let mut penalty = 0;
+        if options.keep_common_tones && self.next_notes.contains(&class) && step != 0 {
+            penalty += RULE_PENALTY;
+        }
+        if options.resolve_tendency_tones {
+            if is(self.leading_tone) && resolves_to(1) && step != 1 {
+                penalty += RULE_PENALTY;
+            }
+            if is(self.seventh)
+                && (resolves_to(-1) || resolves_to(-2))
+                && !(-2..=-1).contains(&step)
+            {
+                penalty += RULE_PENALTY;
+            }
+        }
+        (movement + penalty, movement)
+    }
+
+    // the cheapest way to move from one voicing to the other.
+    // the bass always moves to the bass, and the upper voices may swap places when crossing is allowed.
+    // the swaps are found as a minimum cost assignment, and keeping the voices in order wins ties.
+    fn motion(&self, previous: &[Pitch], next: &[Pitch], options: &VoiceLeadingOptions) -> Motion {
+        let in_order: Vec<usize> = (0..previous.len()).collect();
+        let mut best = self.motion_to(previous, next, in_order, options);
+        if !options.avoid_voice_crossing {
+            let costs: Vec<Vec<u32>> = previous[1..]
+                .iter()
+                .map(|&from| {
+                    next[1..]
+                        .iter()
+                        .map(|&to| self.voice_cost(from, to, options).0)
+                        .collect()
+                })
+                .collect();
+            let mut destinations = vec![0];
+            destinations.extend(assignment(&costs).into_iter().map(|column| column + 1));
+            let crossed = self.motion_to(previous, next, destinations, options);
+            if crossed.cost < best.cost {
+                best = crossed;
+            }
+        }
+        best
+    }
+
+    fn motion_to(
+        &self,
+        previous: &[Pitch],
+        next: &[Pitch],
+        destinations: Vec<usize>,
+        options: &VoiceLeadingOptions,
+    ) -> Motion {
+        let (cost, movement) = (0..previous.len()).fold((0, 0), |(cost, movement), voice| {
+            let (voice_cost, voice_movement) =
+                self.voice_cost(previous[voice], next[destinations[voice]], options);
+            (cost + voice_cost, movement + voice_movement)
+        });
+        Motion {
+            cost,
+            movement,
+            destinations,
+        }
+    }
+}
+
+// the column assigned to every row of a square cost matrix so that the total cost is the lowest,
+// found with the Hungarian algorithm in O(n³)
+fn assignment(costs: &[Vec<u32>]) -> Vec<usize> {
+    let size = costs.len();
+    // the potentials and matches are 1-based, with 0 standing for "unmatched"
+    let mut row_potential = vec![0i64; size + 1];
+    let mut column_potential = vec![0i64; size + 1];
+    let mut row_of_column = vec![0usize; size + 1];
+    let mut way = vec![0usize; size + 1];
+    for row in 1..=size {
+        row_of_column[0] = row;
+        let mut column = 0;
+        let mut slack = vec![i64::MAX; size + 1];
+        let mut used = vec![false; size + 1];
+        loop {
+            used[column] = true;
+            let current_row = row_of_column[column];
+            let mut delta = i64::MAX;
+            let mut next_column = 0;
+            for candidate in 1..=size {
+                if used[candidate] {
+                    continue;
+                }
+                let reduced = costs[current_row - 1][candidate - 1] as i64
+                    - row_potential[current_row]
+                    - column_potential[candidate];
+                if reduced < slack[candidate] {
+                    slack[candidate] = reduced;
+                    way[candidate] = column;
+                }
+                if slack[candidate] < delta {
+                    delta = slack[candidate];
+                    next_column = candidate;
+                }
+            }
+            for candidate in 0..=size {
+                if used[candidate] {
+                    row_potential[row_of_column[candidate]] += delta;
+                    column_potential[candidate] -= delta;
+                } else {
+                    slack[candidate] -= delta;
+                }
+            }
+            column = next_column;
+            if row_of_column[column] == 0 {
+                break;
+            }
+        }
+        while column != 0 {
+            let previous = way[column];
+            row_of_column[column] = row_of_column[previous];
+            column = previous;
+        }
+    }
+    let mut result = vec![0; size];
+    for column in 1..=size {
+        result[row_of_column[column] - 1] = column - 1;
+    }
+    result
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod voice_leading_test {
+    use super::*;
+    use crate::chord::ChordSeventh;
+
+    fn pitch(name: &str) -> Pitch {
+        Pitch::try_from(name).unwrap()
+    }
+
+    fn chord(root: &str, quality: ChordQuality) -> Chord {
+        Chord::new(Note::try_from(root).unwrap(), quality)
+    }
+
+    fn options() -> VoiceLeadingOptions {
+        VoiceLeadingOptions::new(4, pitch("E2"), pitch("G5"))
+    }
+
+    #[test]
+    fn keeps_the_bass_in_the_lowest_voice() {
+        let chords = [
+            chord("C", ChordQuality::Major),
+            chord("F", ChordQuality::Major),
+            chord("G", ChordQuality::Major),
+            chord("C", ChordQuality::Major),
+        ];
+        let leading = VoiceLeading::new(&chords, options()).unwrap();
+        let bass: Vec<Note> = leading.voice(0).iter().map(|pitch| pitch.note()).collect();
+        let roots: Vec<Note> = chords.iter().map(|chord| chord.root()).collect();
+        assert_eq!(bass, roots);
+        assert!(leading
+            .voicings()
+            .iter()
+            .all(|voicing| voicing.windows(2).all(|pair| pair[0] < pair[1])));
+    }
+
+    #[test]
+    fn moves_upper_voices_as_little_as_possible() {
+        let chords = [
+            chord("C", ChordQuality::Major),
+            chord("A", ChordQuality::Minor),
+        ];
+        let leading = VoiceLeading::new(&chords, options()).unwrap();
+        let voicings = leading.voicings();
+        // C E G to A C E: two voices hold their notes and one moves from G up to A
+        let upper_movement: i16 = (1..4)
+            .map(|voice| {
+                (voicings[1][voice].midi_number() - voicings[0][voice].midi_number()).abs()
+            })
+            .sum();
+        assert_eq!(upper_movement, 2);
+    }
+
+    #[test]
+    fn resolves_tendency_tones() {
+        let g7 = chord("G", ChordQuality::Major).with_seventh(ChordSeventh::Minor);
+        let chords = [g7, chord("C", ChordQuality::Major)];
+        let options = options().with_tendency_tones(true);
+        let leading = VoiceLeading::new(&chords, options).unwrap();
+        let voicings = leading.voicings();
+        for (&from, &to) in voicings[0].iter().zip(voicings[1].iter()).skip(1) {
+            let step = to.midi_number() - from.midi_number();
+            if from.note() == Note::try_from("B").unwrap() {
+                assert_eq!(step, 1, "the leading tone B resolves up to C");
+            }
+            if from.note() == Note::try_from("F").unwrap() {
+                assert_eq!(step, -1, "the seventh F resolves down to E");
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_common_tones() {
+        let chords = [
+            chord("C", ChordQuality::Major),
+            chord("E", ChordQuality::Minor).with_bass(Note::try_from("G").unwrap()),
+        ];
+        let options = options().with_common_tones(true);
+        let leading = VoiceLeading::new(&chords, options).unwrap();
+        let voicings = leading.voicings();
+        for (from, to) in voicings[0].iter().zip(voicings[1].iter()).skip(1) {
+            let note = from.note();
+            if note == Note::try_from("E").unwrap() || note == Note::try_from("G").unwrap() {
+                assert_eq!(from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn voice_crossing_never_costs_more() {
+        let chords = [
+            chord("C", ChordQuality::Major),
+            chord("D", ChordQuality::Minor),
+            chord("G", ChordQuality::Major),
+        ];
+        let strict = VoiceLeading::new(&chords, options()).unwrap();
+        let crossing = VoiceLeading::new(&chords, options().with_voice_crossing(true)).unwrap();
+        assert!(crossing.total_movement() <= strict.total_movement());
+        assert_eq!(crossing.voicings().len(), 3);
+    }
+
+    #[test]
+    fn crosses_voices_to_resolve_tendency_tones() {
+        let chords = [
+            chord("C", ChordQuality::Major).with_seventh(ChordSeventh::Minor),
+            chord("D", ChordQuality::Minor).with_seventh(ChordSeventh::Minor),
+        ];
+        let options =
+            VoiceLeadingOptions::new(4, pitch("C3"), pitch("C5")).with_tendency_tones(true);
+        let crossed = |voicing: &Vec<Pitch>| voicing.windows(2).any(|pair| pair[0] > pair[1]);
+
+        let strict = VoiceLeading::new(&chords, options).unwrap();
+        assert!(!strict.voicings().iter().any(crossed));
+
+        let crossing = VoiceLeading::new(&chords, options.with_voice_crossing(true)).unwrap();
+        assert!(crossed(&crossing.voicings()[1]));
+        // the seventh of C7 resolves down by step to the fifth of Dm7, below the voice under it
+        assert_eq!(crossing.voice(3), [pitch("Bb3"), pitch("A3")]);
+    }
+
+    #[test]
+    fn leads_many_voices_with_crossing_quickly() {
+        let chords = [
+            chord("C", ChordQuality::Major).with_seventh(ChordSeventh::Major),
+            chord("A", ChordQuality::Minor).with_seventh(ChordSeventh::Minor),
+            chord("D", ChordQuality::Minor).with_seventh(ChordSeventh::Minor),
+            chord("G", ChordQuality::Major).with_seventh(ChordSeventh::Minor),
+        ];
+        let options =
+            VoiceLeadingOptions::new(5, pitch("C3"), pitch("C5")).with_voice_crossing(true);
+        let leading = VoiceLeading::new(&chords, options).unwrap();
+        assert_eq!(leading.voicings().len(), 4);
+    }
+
+    #[test]
+    fn fails_when_a_chord_does_not_fit() {
+        let chords = [chord("C", ChordQuality::Major)];
+        let options = VoiceLeadingOptions::new(4, pitch("C4"), pitch("D4"));
+        assert!(VoiceLeading::new(&chords, options).is_err());
+        assert!(VoiceLeading::new(
+            &chords,
+            VoiceLeadingOptions::new(1, pitch("C2"), pitch("C6"))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let chords = [
+            chord("C", ChordQuality::Major),
+            chord("G", ChordQuality::Major),
+        ];
+        let top = VoiceLeadingOptions::new(3, pitch("C126"), pitch("B127"));
+        let leading = VoiceLeading::new(&chords, top).unwrap();
+        assert!(leading
+            .voicings()
+            .iter()
+            .flatten()
+            .all(|pitch| pitch.octave() >= 126));
+        let bottom = VoiceLeadingOptions::new(3, pitch("C-128"), pitch("B-127"));
+        let leading = VoiceLeading::new(&chords, bottom).unwrap();
+        assert!(leading
+            .voicings()
+            .iter()
+            .flatten()
+            .all(|pitch| pitch.octave() <= -127));
+    }
+}
@@ -0,0 +1,340 @@
+use crate::note::{Note, NotePitchInterval, Pitch};
+
+use super::Chord;
+
+// the ways the tones of a chord can be spread over a register.
+// Close stacks every tone within an octave, Open raises the second lowest voice of a close voicing by an octave,
+// and the drop voicings lower the second (Drop2), third (Drop3), or second and fourth (Drop2And4) highest voice
+// of a close voicing by an octave.
+// RootlessA stacks 3-5-7-9 and RootlessB 7-9-3-5, using the 13th in place of the fifth when the chord has one.
+// Shell voicings are the three note root-3-7 and root-7-3 shapes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VoicingKind {
+    Close,
+    Open,
+    Drop2,
+    Drop3,
+    Drop2And4,
+    RootlessA,
+    RootlessB,
+    Shell,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Voicing {
+    kind: VoicingKind,
+    pitches: Vec<Pitch>,
+}
+
+impl Voicing {
+    pub fn kind(&self) -> VoicingKind {
+        self.kind
+    }
+    // every pitch of the voicing from lowest to highest
+    pub fn pitches(&self) -> &[Pitch] {
+        &self.pitches
+    }
+    pub fn lowest(&self) -> Pitch {
+        self.pitches[0]
+    }
+    pub fn highest(&self) -> Pitch {
+        self.pitches[self.pitches.len() - 1]
+    }
+    // the distance in semitones between the lowest and the highest pitch
+    pub fn span(&self) -> u8 {
+        (self.highest().midi_number() - self.lowest().midi_number()) as u8
+    }
+}
+
+impl Chord {
+    // every placement of the given kind of voicing with all of its pitches between low and high inclusive,
+    // ordered from the lowest placement up.
+    // returns an empty list when the chord can't be voiced that way, e.g. a drop-3 voicing of a triad,
+    // or a rootless voicing of a chord without a seventh or sixth.
+    // slash chords keep their bass: a chord tone in the bass must be the lowest pitch,
+    // and any other bass note is placed below the voicing.
+    pub fn voicings(&self, kind: VoicingKind, low: Pitch, high: Pitch) -> Vec<Voicing> {
+        let shapes = match self.voicing_shapes(kind) {
+            Some(shapes) => shapes,
+            None => return vec![],
+        };
+        let bass = self.bass();
+        let bass_is_chord_tone = self.inversion_for_bass(&bass).is_some();
+
+        let mut result: Vec<Voicing> = vec![];
+        for shape in shapes.iter() {
+            for octave in low.octave().saturating_sub(1)..=high.octave().saturating_add(1) {
+                let mut pitches = match stack(Pitch::new(shape[0], octave), shape) {
+                    Some(pitches) => pitches,
+                    None => continue,
+                };
+                if apply_drops(kind, &mut pitches).is_none() {
+                    continue;
+                }
+                if bass != self.root() {
+                    if bass_is_chord_tone && !pitches[0].note().is_enharmonic(&bass) {
+                        continue;
+                    } else if !bass_is_chord_tone {
+                        match pitches[0].checked_next_below(bass) {
+                            Some(below) => pitches.insert(0, below),
+                            None => continue,
+                        }
+                    }
+                }
+                let in_range = pitches.iter().all(|pitch| {
+                    pitch.midi_number() >= low.midi_number()
+                        && pitch.midi_number() <= high.midi_number()
+                });
+                let voicing = Voicing { kind, pitches };
+                if in_range && !result.contains(&voicing) {
+                    result.push(voicing);
+                }
+            }
+        }
+        result.sort_by(|a, b| a.pitches.cmp(&b.pitches));
+        result
+    }
+
+    // the notes of each voicing from the bottom up, before they're spread over octaves
+    fn voicing_shapes(&self, kind: VoicingKind) -> Option<Vec<Vec<Note>>> {
+        use VoicingKind::*;
+
+        let tone =
+            |interval: NotePitchInterval| self.root().checked_by_interval_ascending(interval);
+        let tones = self.notes_checked()?;
+
+        let minimum_voices = match kind {
+            Close => 1,
+            Open | Drop2 => 3,
+            _ => 4,
+        };
+        let shapes = match kind {
+            Close | Open | Drop2 | Drop3 | Drop2And4 => {
+                if tones.len() < minimum_voices {
+                    return None;
+                }
+                (0..tones.len())
+                    .map(|index| {
+                        let mut rotated = tones.clone();
+                        rotated.rotate_left(index);
+                        rotated
+                    })
+                    .collect()
+            }
+            RootlessA | RootlessB => {
+                let seventh = self.seventh_or_sixth()?;
+                let third = tone(self.interval_at_degree(3)?)?;
+                let ninth = tone(
+                    self.interval_at_degree(9)
+                        .unwrap_or(NotePitchInterval::MajorSecond),
+                )?;
+                let thirteenth = self.interval_at_degree(13).filter(|&interval| {
+                    self.seventh_interval().is_some() && tone(interval) != Some(seventh)
+                });
+                let fifth = match thirteenth {
+                    Some(interval) => tone(interval)?,
+                    None => self.fifth(),
+                };
+                if kind == RootlessA {
+                    vec![vec![third, fifth, seventh, ninth]]
+                } else {
+                    vec![vec![seventh, ninth, third, fifth]]
+                }
+            }
+            Shell => {
+                let seventh = self.seventh_or_sixth()?;
+                let third = tone(self.interval_at_degree(3)?)?;
+                vec![
+                    vec![self.root(), third, seventh],
+                    vec![self.root(), seventh, third],
+                ]
+            }
+        };
+        Some(shapes)
+    }
+
+    // sixth chords use their sixth where voicings call for a seventh
+    fn seventh_or_sixth(&self) -> Option<Note> {
+        self.seventh_interval()
+            .or_else(|| {
+                self.intervals()
+                    .iter()
+                    .copied()
+                    .find(|&interval| interval == NotePitchInterval::MajorSixth)
+            })
+            .and_then(|interval| self.root().checked_by_interval_ascending(interval))
+    }
+}
+
+// places each note just above the previous one, starting from the given pitch.
+// None when a note would go above octave 127.
+pub(super) fn stack(bottom: Pitch, notes: &[Note]) -> Option<Vec<Pitch>> {
+    let mut result = vec![bottom];
+    for &note in notes[1..].iter() {
+        let next = result[result.len() - 1].checked_next_above(note)?;
+        result.push(next);
+    }
+    Some(result)
+}
+
+// moves the dropped voices an octave. None when one would go past octave -128 or 127.
+fn apply_drops(kind: VoicingKind, pitches: &mut [Pitch]) -> Option<()> {
+    let len = pitches.len();
+    let (voices, octaves): (&[usize], i8) = match kind {
+        VoicingKind::Open => (&[1], 1),
+        VoicingKind::Drop2 => (&[len - 2], -1),
+        VoicingKind::Drop3 => (&[len - 3], -1),
+        VoicingKind::Drop2And4 => (&[len - 2, len - 4], -1),
+        _ => (&[], 0),
+    };
+    for &voice in voices {
+        pitches[voice] = pitches[voice].checked_by_octaves(octaves)?;
+    }
+    pitches.sort();
+    Some(())
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod voicing_test {
+    use super::*;
+    use crate::chord::{ChordExtensionKind, ChordQuality, ChordSeventh};
+    use VoicingKind::*;
+
+    fn pitch(name: &str) -> Pitch {
+        Pitch::try_from(name).unwrap()
+    }
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn printed(voicing: &Voicing) -> String {
+        voicing
+            .pitches()
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn printed_all(voicings: &[Voicing]) -> Vec<String> {
+        voicings.iter().map(printed).collect()
+    }
+
+    fn cmaj7() -> Chord {
+        Chord::new(note("C"), ChordQuality::Major).with_seventh(ChordSeventh::Major)
+    }
+
+    #[test]
+    fn close_voicings_cover_every_inversion_in_range() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        let voicings = c.voicings(Close, pitch("C4"), pitch("C5"));
+        assert_eq!(
+            printed_all(&voicings),
+            ["C4 E4 G4", "E4 G4 C5"],
+            "G4 C5 E5 is out of range"
+        );
+    }
+
+    #[test]
+    fn open_voicing_spreads_triad() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        let voicings = c.voicings(Open, pitch("C3"), pitch("E4"));
+        assert_eq!(printed_all(&voicings), ["C3 G3 E4"]);
+    }
+
+    #[test]
+    fn drop_voicings_lower_voices_of_close_voicing() {
+        let drop2 = cmaj7().voicings(Drop2, pitch("G3"), pitch("E5"));
+        assert!(printed_all(&drop2).contains(&"G3 C4 E4 B4".to_string()));
+
+        let drop3 = cmaj7().voicings(Drop3, pitch("E3"), pitch("C5"));
+        assert!(printed_all(&drop3).contains(&"E3 C4 G4 B4".to_string()));
+
+        let drop24 = cmaj7().voicings(Drop2And4, pitch("C3"), pitch("B4"));
+        assert!(printed_all(&drop24).contains(&"C3 G3 E4 B4".to_string()));
+    }
+
+    #[test]
+    fn drop_voicings_need_enough_voices() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert!(c.voicings(Drop3, pitch("C2"), pitch("C6")).is_empty());
+        assert!(c.voicings(Drop2And4, pitch("C2"), pitch("C6")).is_empty());
+    }
+
+    #[test]
+    fn rootless_voicings_use_thirteenth_for_fifth() {
+        let dm9 =
+            Chord::new(note("D"), ChordQuality::Minor).with_extension(ChordExtensionKind::Ninth);
+        let a = dm9.voicings(RootlessA, pitch("E3"), pitch("G4"));
+        assert_eq!(printed_all(&a), ["F3 A3 C4 E4"]);
+        let b = dm9.voicings(RootlessB, pitch("C3"), pitch("B3"));
+        assert_eq!(printed_all(&b), ["C3 E3 F3 A3"]);
+
+        let g13 = Chord::new(note("G"), ChordQuality::Major)
+            .with_extension(ChordExtensionKind::Thirteenth);
+        let a = g13.voicings(RootlessA, pitch("A3"), pitch("C5"));
+        assert_eq!(printed_all(&a), ["B3 E4 F4 A4"]);
+    }
+
+    #[test]
+    fn rootless_voicings_need_a_seventh_or_sixth() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert!(c.voicings(RootlessA, pitch("C2"), pitch("C6")).is_empty());
+    }
+
+    #[test]
+    fn shell_voicings() {
+        let voicings = cmaj7().voicings(Shell, pitch("C3"), pitch("C4"));
+        assert_eq!(printed_all(&voicings), ["C3 E3 B3"]);
+        let voicings = cmaj7().voicings(Shell, pitch("C3"), pitch("E4"));
+        assert_eq!(printed_all(&voicings), ["C3 E3 B3", "C3 B3 E4"]);
+    }
+
+    #[test]
+    fn slash_bass_stays_lowest() {
+        let c_over_e = Chord::new(note("C"), ChordQuality::Major).with_bass(note("E"));
+        let voicings = c_over_e.voicings(Close, pitch("C4"), pitch("C5"));
+        assert_eq!(printed_all(&voicings), ["E4 G4 C5"]);
+
+        let c_over_d = Chord::new(note("C"), ChordQuality::Major).with_bass(note("D"));
+        let voicings = c_over_d.voicings(Close, pitch("C3"), pitch("G4"));
+        assert!(printed_all(&voicings).contains(&"D3 C4 E4 G4".to_string()));
+        assert!(voicings
+            .iter()
+            .all(|voicing| voicing.lowest().note() == note("D")));
+    }
+
+    #[test]
+    fn span_measures_semitones() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        let voicings = c.voicings(Close, pitch("C4"), pitch("G4"));
+        assert_eq!(voicings[0].span(), 7);
+        assert_eq!(voicings[0].kind(), Close);
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let c = Chord::new(note("C"), ChordQuality::Major);
+        assert_eq!(
+            printed_all(&c.voicings(Close, pitch("C126"), pitch("C127"))),
+            ["C126 E126 G126", "E126 G126 C127"]
+        );
+        assert_eq!(
+            printed_all(&c.voicings(Close, pitch("C-128"), pitch("C-127"))),
+            ["C-128 E-128 G-128", "E-128 G-128 C-127"]
+        );
+        assert_eq!(
+            printed_all(&c.voicings(Open, pitch("C125"), pitch("C127"))),
+            ["C125 G125 E126", "E125 C126 G126", "G125 E126 C127"]
+        );
+        assert_eq!(
+            printed_all(&cmaj7().voicings(Drop2, pitch("C-128"), pitch("C-127"))),
+            Vec::<String>::new()
+        );
+    }
+}
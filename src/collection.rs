@@ -0,0 +1,195 @@
+use std::collections::BTreeSet;
+
+use crate::chord::Chord;
+use crate::note::{Note, NotePitchInterval};
+use crate::scale::Scale;
+
+// an ordered set of notes starting from a root, like the tones of a chord or the degrees of a scale.
+// the set operations compare notes enharmonically and keep the spelling of this collection first,
+// so Cm and B major have Eb and D# in common, returned as Eb.
+pub trait PitchCollection {
+    // every note once, starting from the root
+    fn collection_notes(&self) -> Vec<Note>;
+
+    fn iter_notes(&self) -> impl Iterator<Item = Note> {
+        self.collection_notes().into_iter()
+    }
+
+    // whether the note is in the collection with the same spelling
+    fn includes(&self, note: &Note) -> bool {
+        self.collection_notes().contains(note)
+    }
+
+    // whether the note or one enharmonic to it is in the collection
+    fn includes_enharmonic(&self, note: &Note) -> bool {
+        self.iter_notes().any(|tone| tone.is_enharmonic(note))
+    }
+
+    // the pitch classes of the notes, where C is 0
+    fn pitch_classes(&self) -> BTreeSet<u8> {
+        self.iter_notes().map(|note| note.pitch_class()).collect()
+    }
+
+    // the interval from the root up to every other note.
+    // notes whose spelling doesn't make a simple interval with the root are left out.
+    fn intervals_from_root(&self) -> Vec<NotePitchInterval> {
+        let notes = self.collection_notes();
+        let root = match notes.first() {
+            Some(root) => *root,
+            None => return vec![],
+        };
+        notes[1..]
+            .iter()
+            .filter_map(|note| root.interval_to(note))
+            .collect()
+    }
+
+    // the notes of this collection that the other one also has
+    fn common_tones<P: PitchCollection + ?Sized>(&self, other: &P) -> Vec<Note> {
+        self.iter_notes()
+            .filter(|note| other.includes_enharmonic(note))
+            .collect()
+    }
+
+    // the notes of this collection followed by the ones only the other has
+    fn union<P: PitchCollection + ?Sized>(&self, other: &P) -> Vec<Note> {
+        let mut result = self.collection_notes();
+        for note in other.iter_notes() {
+            if !result.iter().any(|existing| existing.is_enharmonic(&note)) {
+                result.push(note);
+            }
+        }
+        result
+    }
+
+    fn intersection<P: PitchCollection + ?Sized>(&self, other: &P) -> Vec<Note> {
+        self.common_tones(other)
+    }
+
+    // the notes of this collection the other doesn't have
+    fn difference<P: PitchCollection + ?Sized>(&self, other: &P) -> Vec<Note> {
+        self.iter_notes()
+            .filter(|note| !other.includes_enharmonic(note))
+            .collect()
+    }
+}
+
+// the chord tones in root position, followed by the bass when it isn't one of them.
+// tones that would need more than two accidentals, like the diminished seventh of Fbdim7, are left out.
+impl PitchCollection for Chord {
+    fn collection_notes(&self) -> Vec<Note> {
+        let mut result = vec![self.root()];
+        result.extend(
+            self.intervals()
+                .iter()
+                .filter_map(|&interval| self.root().checked_by_interval_ascending(interval)),
+        );
+        if !result.contains(&self.bass()) {
+            result.push(self.bass());
+        }
+        result
+    }
+}
+
+// the degrees of the scale, whatever its direction
+impl PitchCollection for Scale {
+    fn collection_notes(&self) -> Vec<Note> {
+        self.degrees().to_vec()
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod collection_test {
+    use super::*;
+    use crate::chord::{ChordQuality, ChordSeventh};
+    use crate::scale::{ScaleDirection, ScaleKind};
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn notes(names: &str) -> Vec<Note> {
+        names.split(' ').map(note).collect()
+    }
+
+    fn scale(root: &str, kind: ScaleKind) -> Scale {
+        Scale::new(note(root), kind, ScaleDirection::Ascending)
+    }
+
+    // works on any collection, to check the trait can be used generically
+    fn describe<P: PitchCollection>(collection: &P) -> String {
+        collection
+            .iter_notes()
+            .map(|note| note.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn lists_notes_of_chords_and_scales() {
+        let g7 = Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor);
+        assert_eq!(describe(&g7), "G B D F");
+        assert_eq!(describe(&g7.clone().with_bass(note("F"))), "G B D F");
+        assert_eq!(
+            describe(&Chord::new(note("C"), ChordQuality::Major).with_bass(note("D"))),
+            "C E G D"
+        );
+        let descending = Scale::new(note("A"), ScaleKind::Minor, ScaleDirection::Descending);
+        assert_eq!(describe(&descending), "A B C D E F G");
+    }
+
+    #[test]
+    fn checks_membership_and_pitch_classes() {
+        let d_major = scale("D", ScaleKind::Major);
+        assert!(d_major.includes(&note("F#")));
+        assert!(!d_major.includes(&note("Gb")));
+        assert!(d_major.includes_enharmonic(&note("Gb")));
+        assert_eq!(
+            d_major.pitch_classes().into_iter().collect::<Vec<u8>>(),
+            [1, 2, 4, 6, 7, 9, 11]
+        );
+    }
+
+    #[test]
+    fn measures_intervals_from_the_root() {
+        use NotePitchInterval::*;
+        let cm7 = Chord::new(note("C"), ChordQuality::Minor).with_seventh(ChordSeventh::Minor);
+        assert_eq!(
+            cm7.intervals_from_root(),
+            [MinorThird, PerfectFifth, MinorSeventh]
+        );
+        assert_eq!(
+            scale("C", ScaleKind::MajorPentatonic).intervals_from_root(),
+            [MajorSecond, MajorThird, PerfectFifth, MajorSixth]
+        );
+    }
+
+    #[test]
+    fn combines_chords_and_scales() {
+        let c_minor = Chord::new(note("C"), ChordQuality::Minor);
+        let b_major = Chord::new(note("B"), ChordQuality::Major);
+        assert_eq!(c_minor.common_tones(&b_major), notes("Eb"));
+        assert_eq!(c_minor.union(&b_major), notes("C Eb G B F#"));
+        assert_eq!(c_minor.difference(&b_major), notes("C G"));
+
+        let c_major = scale("C", ScaleKind::Major);
+        let g7 = Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor);
+        assert_eq!(c_major.intersection(&g7), notes("D F G B"));
+        assert_eq!(g7.difference(&c_major), vec![]);
+        assert_eq!(
+            c_major.difference(&scale("G", ScaleKind::Major)),
+            notes("F")
+        );
+    }
+
+    #[test]
+    fn leaves_out_tones_that_cannot_be_spelled() {
+        let f_flat_dim7 =
+            Chord::new(note("Fb"), ChordQuality::Diminished).with_seventh(ChordSeventh::Diminished);
+        assert_eq!(describe(&f_flat_dim7), "Fb Abb Cbb");
+    }
+}
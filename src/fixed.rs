@@ -0,0 +1,106 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+
+// a list with room for at most N items that lives on the stack, so building one never allocates.
+// the unused slots hold a copy of the filler given to new, and are never read.
+#[derive(Clone, Copy)]
+pub(crate) struct FixedVec<T: Copy, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> FixedVec<T, N> {
+    pub(crate) fn new(filler: T) -> Self {
+        Self {
+            items: [filler; N],
+            len: 0,
+        }
+    }
+
+    // None when the items don't fit, or when there are none to fill the unused slots with
+    pub(crate) fn from_slice(items: &[T]) -> Option<Self> {
+        let mut result = Self::new(*items.first()?);
+        for &item in items {
+            result.push(item)?;
+        }
+        Some(result)
+    }
+
+    // adds the item at the end, or returns None when the list is full
+    pub(crate) fn push(&mut self, item: T) -> Option<()> {
+        *self.items.get_mut(self.len)? = item;
+        self.len += 1;
+        Some(())
+    }
+
+    pub(crate) fn retain(&mut self, keep: impl Fn(&T) -> bool) {
+        let mut kept = 0;
+        for index in 0..self.len {
+            if keep(&self.items[index]) {
+                self.items[kept] = self.items[index];
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+}
+
+impl<T: Copy, const N: usize> Deref for FixedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+impl<T: Copy, const N: usize> DerefMut for FixedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items[..self.len]
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> PartialEq for FixedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for FixedVec<T, N> {}
+
+impl<T: Copy + Debug, const N: usize> Debug for FixedVec<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod fixed_vec_test {
+    use super::*;
+
+    #[test]
+    fn holds_up_to_its_capacity() {
+        let mut list: FixedVec<u8, 3> = FixedVec::new(0);
+        assert!(list.is_empty());
+        assert_eq!(list.push(1), Some(()));
+        assert_eq!(list.push(2), Some(()));
+        assert_eq!(list.push(3), Some(()));
+        assert_eq!(list.push(4), None);
+        assert_eq!(&*list, [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn compares_only_the_items() {
+        let mut list: FixedVec<u8, 4> = FixedVec::from_slice(&[5, 1, 4, 2]).unwrap();
+        list.retain(|&item| item != 4);
+        list.sort();
+        assert_eq!(list, FixedVec::from_slice(&[1, 2, 5]).unwrap());
+        assert_eq!(format!("{list:?}"), "[1, 2, 5]");
+        assert!(FixedVec::<u8, 2>::from_slice(&[1, 2, 3]).is_none());
+        assert!(FixedVec::<u8, 2>::from_slice(&[]).is_none());
+    }
+}
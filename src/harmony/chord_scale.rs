@@ -0,0 +1,319 @@
+use crate::chord::Chord;
+use crate::note::Note;
+use crate::scale::{Scale, ScaleDirection, ScaleKind};
+
+use super::Key;
+
+// the scales looked at for a chord, in the order they're suggested when more than one fits
+const CHORD_SCALE_KINDS: [ScaleKind; 21] = [
+    ScaleKind::Ionian,
+    ScaleKind::Dorian,
+    ScaleKind::Phrygian,
+    ScaleKind::Lydian,
+    ScaleKind::Mixolydian,
+    ScaleKind::Aeolian,
+    ScaleKind::Locrian,
+    ScaleKind::MelodicMinor,
+    ScaleKind::DorianFlatTwo,
+    ScaleKind::LydianAugmented,
+    ScaleKind::LydianDominant,
+    ScaleKind::MixolydianFlatSix,
+    ScaleKind::LocrianSharpTwo,
+    ScaleKind::Altered,
+    ScaleKind::HarmonicMinor,
+    ScaleKind::LocrianSharpSix,
+    ScaleKind::IonianSharpFive,
+    ScaleKind::DorianSharpFour,
+    ScaleKind::PhrygianDominant,
+    ScaleKind::LydianSharpTwo,
+    ScaleKind::SuperLocrianDoubleFlatSeven,
+];
+
+// the part a note of a chord scale plays against the chord.
+// tensions can be added to the chord freely, while avoid notes clash with it when sustained.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScaleToneRole {
+    ChordTone,
+    Tension,
+    AvoidNote,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScaleTone {
+    note: Note,
+    role: ScaleToneRole,
+}
+
+impl ScaleTone {
+    pub fn note(&self) -> Note {
+        self.note
+    }
+    pub fn role(&self) -> ScaleToneRole {
+        self.role
+    }
+}
+
+// a scale built on the root of a chord that contains every tone of the chord.
+// the scale's other notes are avoid notes when they lie a half step above the root, third, fifth or seventh,
+// except for the b9 of a dominant seventh chord, and tensions otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordScale {
+    scale: Scale,
+    tones: Vec<ScaleTone>,
+}
+
+impl ChordScale {
+    // the chord scale of the given kind, or None if the scale is missing one of the chord's tones
+    // or can't be spelled on the chord's root, like Phrygian on Fb.
+    // chord tones keep the spelling they have in the chord, e.g. the B of G7alt instead of the Cb of G altered.
+    fn new(chord: &Chord, kind: ScaleKind) -> Option<Self> {
+        let root = chord.root();
+        let scale = Scale::checked_new(root, kind, ScaleDirection::Ascending)?;
+        let scale_notes = scale.degrees();
+        let chord_notes = chord.notes();
+        let fits = chord_notes
+            .iter()
+            .all(|note| scale_notes.iter().any(|tone| tone.is_enharmonic(note)));
+        if !fits {
+            return None;
+        }
+
+        let mut structural = vec![root, chord.third()];
+        if let Some(fifth) = chord.interval_at_degree(5) {
+            structural.push(root.by_interval_ascending(fifth));
+        }
+        structural.extend(chord.seventh());
+        let is_dominant = chord.is_dominant();
+
+        let tones = scale_notes
+            .iter()
+            .map(|&note| {
+                if let Some(&chord_note) = chord_notes.iter().find(|tone| tone.is_enharmonic(&note))
+                {
+                    return ScaleTone {
+                        note: chord_note,
+                        role: ScaleToneRole::ChordTone,
+                    };
+                }
+                let is_flat_ninth = root.semitones_to(&note) == 1;
+                let clashes = structural.iter().any(|tone| tone.semitones_to(&note) == 1);
+                let role = if clashes && !(is_dominant && is_flat_ninth) {
+                    ScaleToneRole::AvoidNote
+                } else {
+                    ScaleToneRole::Tension
+                };
+                ScaleTone { note, role }
+            })
+            .collect();
+        Some(ChordScale { scale, tones })
+    }
+
+    pub fn root(&self) -> Note {
+        self.scale.root()
+    }
+    pub fn kind(&self) -> &ScaleKind {
+        self.scale.kind()
+    }
+    // every note of the scale from the root up, with its role against the chord
+    pub fn tones(&self) -> &[ScaleTone] {
+        &self.tones
+    }
+    pub fn scale(&self) -> Scale {
+        self.scale.clone()
+    }
+    pub fn chord_tones(&self) -> Vec<Note> {
+        self.notes_with_role(ScaleToneRole::ChordTone)
+    }
+    pub fn tensions(&self) -> Vec<Note> {
+        self.notes_with_role(ScaleToneRole::Tension)
+    }
+    pub fn avoid_notes(&self) -> Vec<Note> {
+        self.notes_with_role(ScaleToneRole::AvoidNote)
+    }
+
+    fn notes_with_role(&self, role: ScaleToneRole) -> Vec<Note> {
+        self.tones
+            .iter()
+            .filter(|tone| tone.role == role)
+            .map(|tone| tone.note)
+            .collect()
+    }
+}
+
+impl Chord {
+    // the heptatonic scales on the chord's root that contain every tone of the chord.
+    // the modes of the major scale come first, then the melodic and harmonic minor scales and their modes.
+    // e.g. G7 fits Mixolydian, Lydian dominant, Mixolydian b6 and Phrygian dominant, and G7alt only the altered scale.
+    pub fn chord_scales(&self) -> Vec<ChordScale> {
+        CHORD_SCALE_KINDS
+            .iter()
+            .filter_map(|kind| ChordScale::new(self, kind.clone()))
+            .collect()
+    }
+}
+
+impl Key {
+    // the chord scales of the chord, with the one made of the key's own notes first.
+    // e.g. Dm7 in C major is Dorian before Phrygian and Aeolian, but Aeolian first in F major.
+    pub fn chord_scales(&self, chord: &Chord) -> Vec<ChordScale> {
+        let mut result = chord.chord_scales();
+        let key_notes = self.scale().notes();
+        let diatonic = result.iter().position(|chord_scale| {
+            chord_scale
+                .tones
+                .iter()
+                .all(|tone| key_notes.contains(&tone.note))
+        });
+        if let Some(index) = diatonic {
+            let chord_scale = result.remove(index);
+            result.insert(0, chord_scale);
+        }
+        result
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod chord_scale_test {
+    use super::*;
+    use crate::chord::{ChordAlteration, ChordQuality, ChordSeventh};
+    use crate::harmony::KeyMode;
+    use ScaleKind::*;
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn names(notes: Vec<Note>) -> String {
+        notes
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn kinds(chord_scales: &[ChordScale]) -> Vec<ScaleKind> {
+        chord_scales
+            .iter()
+            .map(|chord_scale| chord_scale.kind().clone())
+            .collect()
+    }
+
+    fn g7() -> Chord {
+        Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor)
+    }
+
+    fn dm7() -> Chord {
+        Chord::new(note("D"), ChordQuality::Minor).with_seventh(ChordSeventh::Minor)
+    }
+
+    #[test]
+    fn dominant_seventh_fits_mixolydian_first() {
+        let chord_scales = g7().chord_scales();
+        assert_eq!(
+            kinds(&chord_scales),
+            [
+                Mixolydian,
+                LydianDominant,
+                MixolydianFlatSix,
+                PhrygianDominant
+            ]
+        );
+
+        let mixolydian = &chord_scales[0];
+        assert_eq!(names(mixolydian.chord_tones()), "G B D F");
+        assert_eq!(names(mixolydian.tensions()), "A E");
+        assert_eq!(names(mixolydian.avoid_notes()), "C");
+        assert!(chord_scales[1].avoid_notes().is_empty());
+    }
+
+    #[test]
+    fn altered_dominant_fits_altered_scale() {
+        let g7alt = g7()
+            .with_alteration(ChordAlteration::FlatNinth)
+            .with_alteration(ChordAlteration::SharpNinth)
+            .with_alteration(ChordAlteration::SharpFifth);
+        let chord_scales = g7alt.chord_scales();
+        assert_eq!(kinds(&chord_scales), [Altered]);
+
+        let altered = &chord_scales[0];
+        assert_eq!(
+            names(altered.tones().iter().map(|tone| tone.note()).collect()),
+            "G Ab A# B Db D# F"
+        );
+        assert_eq!(names(altered.tensions()), "Db");
+        assert!(altered.avoid_notes().is_empty());
+    }
+
+    #[test]
+    fn major_seventh_sharp_eleven_fits_lydian() {
+        let cmaj7 = Chord::new(note("C"), ChordQuality::Major).with_seventh(ChordSeventh::Major);
+        assert_eq!(
+            kinds(&cmaj7.chord_scales()),
+            [Ionian, Lydian, LydianSharpTwo]
+        );
+        assert_eq!(names(cmaj7.chord_scales()[0].avoid_notes()), "F");
+
+        let lydian = cmaj7.with_alteration(ChordAlteration::SharpEleventh);
+        assert_eq!(kinds(&lydian.chord_scales()), [Lydian, LydianSharpTwo]);
+        assert_eq!(names(lydian.chord_scales()[0].tensions()), "D A");
+    }
+
+    #[test]
+    fn marks_half_steps_above_chord_tones_as_avoid_notes() {
+        assert_eq!(
+            kinds(&dm7().chord_scales()),
+            [Dorian, Phrygian, Aeolian, DorianFlatTwo, DorianSharpFour]
+        );
+        let avoid_notes: Vec<String> = dm7()
+            .chord_scales()
+            .into_iter()
+            .map(|chord_scale| names(chord_scale.avoid_notes()))
+            .collect();
+        assert_eq!(avoid_notes, ["", "Eb Bb", "Bb", "Eb", ""]);
+
+        let bm7b5 =
+            Chord::new(note("B"), ChordQuality::Diminished).with_seventh(ChordSeventh::Minor);
+        let locrian = &bm7b5.chord_scales()[0];
+        assert_eq!(locrian.kind(), &Locrian);
+        assert_eq!(names(locrian.avoid_notes()), "C");
+    }
+
+    #[test]
+    fn key_puts_its_own_mode_first() {
+        let c_major = Key::new(note("C"), KeyMode::Major);
+        let f_major = Key::new(note("F"), KeyMode::Major);
+        let bb_major = Key::new(note("Bb"), KeyMode::Major);
+        assert_eq!(kinds(&c_major.chord_scales(&dm7()))[0], Dorian);
+        assert_eq!(kinds(&f_major.chord_scales(&dm7()))[0], Aeolian);
+        assert_eq!(kinds(&bb_major.chord_scales(&dm7()))[0], Phrygian);
+        assert_eq!(kinds(&c_major.chord_scales(&g7()))[0], Mixolydian);
+
+        let fmaj7 = Chord::new(note("F"), ChordQuality::Major).with_seventh(ChordSeventh::Major);
+        assert_eq!(
+            kinds(&c_major.chord_scales(&fmaj7)),
+            [Lydian, Ionian, LydianSharpTwo]
+        );
+    }
+
+    #[test]
+    fn skips_scales_that_cannot_be_spelled() {
+        let f_flat_7 =
+            Chord::new(note("Fb"), ChordQuality::Major).with_seventh(ChordSeventh::Minor);
+        let chord_scales = f_flat_7.chord_scales();
+        assert_eq!(
+            kinds(&chord_scales),
+            [
+                Mixolydian,
+                LydianDominant,
+                MixolydianFlatSix,
+                PhrygianDominant
+            ]
+        );
+        assert_eq!(names(chord_scales[0].chord_tones()), "Fb Ab Cb Ebb");
+        assert_eq!(chord_scales[0].scale().root(), note("Fb"));
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt::{Display, Formatter};
+
+use crate::chord::{Chord, ChordQuality};
+
+use super::{Key, RomanNumeral};
+
+// the role a chord plays in moving away from and back to the tonic
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HarmonicFunction {
+    Tonic,
+    Predominant,
+    Dominant,
+}
+
+impl Display for HarmonicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HarmonicFunction::Tonic => write!(f, "tonic"),
+            HarmonicFunction::Predominant => write!(f, "predominant"),
+            HarmonicFunction::Dominant => write!(f, "dominant"),
+        }
+    }
+}
+
+// the ways a phrase can close.
+// authentic cadences resolve V or vii° to I, and are perfect when V and I are both in root position.
+// half cadences stop on V, and Phrygian half cadences reach it from iv6 in minor.
+// plagal cadences move from IV to I, and deceptive cadences from V to vi.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CadenceKind {
+    PerfectAuthentic,
+    ImperfectAuthentic,
+    Half,
+    PhrygianHalf,
+    Plagal,
+    Deceptive,
+}
+
+impl Display for CadenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CadenceKind::PerfectAuthentic => write!(f, "perfect authentic cadence"),
+            CadenceKind::ImperfectAuthentic => write!(f, "imperfect authentic cadence"),
+            CadenceKind::Half => write!(f, "half cadence"),
+            CadenceKind::PhrygianHalf => write!(f, "Phrygian half cadence"),
+            CadenceKind::Plagal => write!(f, "plagal cadence"),
+            CadenceKind::Deceptive => write!(f, "deceptive cadence"),
+        }
+    }
+}
+
+// a cadence and the index of the chord it arrives on
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cadence {
+    kind: CadenceKind,
+    index: usize,
+}
+
+impl Cadence {
+    pub fn kind(&self) -> CadenceKind {
+        self.kind
+    }
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Key {
+    // the function of the chord on its own.
+    // I, iii and vi are tonic, ii, IV, bVI, the Neapolitan and augmented sixths predominant,
+    // and V, vii° and applied chords dominant.
+    pub fn function(&self, chord: &Chord) -> HarmonicFunction {
+        let numeral = self.analyze(chord);
+        if numeral.target().is_some() {
+            return HarmonicFunction::Dominant;
+        }
+        if numeral.augmented_sixth().is_some() || numeral.is_neapolitan() {
+            return HarmonicFunction::Predominant;
+        }
+        match (numeral.degree(), numeral.accidental()) {
+            (1 | 3, _) | (6, 0) => HarmonicFunction::Tonic,
+            (2 | 4 | 6, _) => HarmonicFunction::Predominant,
+            _ => HarmonicFunction::Dominant,
+        }
+    }
+
+    // the function of every chord in the progression.
+    // a second inversion tonic followed by V is the cadential 6/4 and works as a dominant.
+    pub fn functions(&self, chords: &[Chord]) -> Vec<HarmonicFunction> {
+        let numerals: Vec<RomanNumeral> = chords.iter().map(|chord| self.analyze(chord)).collect();
+        chords
+            .iter()
+            .enumerate()
+            .map(|(index, chord)| {
+                let cadential_six_four = is_tonic(&numerals[index])
+                    && numerals[index].inversion() == 2
+                    && numerals.get(index + 1).is_some_and(is_dominant);
+                match cadential_six_four {
+                    true => HarmonicFunction::Dominant,
+                    false => self.function(chord),
+                }
+            })
+            .collect()
+    }
+
+    // the cadences in the progression, in order.
+    // the soprano isn't known, so authentic cadences are judged perfect from the inversions alone.
+    // a half cadence is a V that ends the progression or moves on to something other than I, vi or another V.
+    pub fn cadences(&self, chords: &[Chord]) -> Vec<Cadence> {
+        let numerals: Vec<RomanNumeral> = chords.iter().map(|chord| self.analyze(chord)).collect();
+        let mut result = vec![];
+        for index in 1..numerals.len() {
+            let previous = &numerals[index - 1];
+            let current = &numerals[index];
+            let next = numerals.get(index + 1);
+
+            let kind = if is_tonic(current) && is_dominant(previous) {
+                let perfect =
+                    previous.degree() == 5 && previous.inversion() == 0 && current.inversion() == 0;
+                match perfect {
+                    true => Some(CadenceKind::PerfectAuthentic),
+                    false => Some(CadenceKind::ImperfectAuthentic),
+                }
+            } else if is_tonic(current) && is_subdominant(previous) {
+                Some(CadenceKind::Plagal)
+            } else if is_submediant(current) && is_dominant(previous) && previous.degree() == 5 {
+                Some(CadenceKind::Deceptive)
+            } else if current.degree() == 5
+                && is_dominant(current)
+                && !is_dominant(previous)
+                && next.is_none_or(|next| {
+                    !is_tonic(next) && !is_submediant(next) && !is_dominant(next)
+                })
+            {
+                let phrygian = is_subdominant(previous)
+                    && previous.quality() == ChordQuality::Minor
+                    && previous.inversion() == 1;
+                match phrygian {
+                    true => Some(CadenceKind::PhrygianHalf),
+                    false => Some(CadenceKind::Half),
+                }
+            } else {
+                None
+            };
+            if let Some(kind) = kind {
+                result.push(Cadence { kind, index });
+            }
+        }
+        result
+    }
+}
+
+fn is_plain(numeral: &RomanNumeral) -> bool {
+    numeral.target().is_none() && numeral.augmented_sixth().is_none()
+}
+
+// a major or minor chord on the tonic, so a Picardy third counts in minor
+fn is_tonic(numeral: &RomanNumeral) -> bool {
+    is_plain(numeral)
+        && numeral.degree() == 1
+        && numeral.accidental() == 0
+        && matches!(numeral.quality(), ChordQuality::Major | ChordQuality::Minor)
+}
+
+// V or vii°, with or without a seventh
+fn is_dominant(numeral: &RomanNumeral) -> bool {
+    let quality = match numeral.degree() {
+        5 => ChordQuality::Major,
+        7 => ChordQuality::Diminished,
+        _ => return false,
+    };
+    is_plain(numeral) && numeral.accidental() == 0 && numeral.quality() == quality
+}
+
+fn is_subdominant(numeral: &RomanNumeral) -> bool {
+    is_plain(numeral)
+        && numeral.degree() == 4
+        && numeral.accidental() == 0
+        && matches!(numeral.quality(), ChordQuality::Major | ChordQuality::Minor)
+}
+
+// vi, or the VI and bVI borrowed from minor
+fn is_submediant(numeral: &RomanNumeral) -> bool {
+    is_plain(numeral)
+        && numeral.degree() == 6
+        && matches!(numeral.accidental(), 0 | -1)
+        && matches!(numeral.quality(), ChordQuality::Major | ChordQuality::Minor)
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod function_test {
+    use super::*;
+    use crate::harmony::KeyMode;
+    use crate::note::Note;
+    use HarmonicFunction::*;
+
+    fn key(tonic: &str, mode: KeyMode) -> Key {
+        Key::new(Note::try_from(tonic).unwrap(), mode)
+    }
+
+    fn cadences(key: &Key, progression: &str) -> Vec<(CadenceKind, usize)> {
+        key.cadences(&key.realize(progression).unwrap())
+            .iter()
+            .map(|cadence| (cadence.kind(), cadence.index()))
+            .collect()
+    }
+
+    #[test]
+    fn labels_functions() {
+        let c_major = key("C", KeyMode::Major);
+        let functions =
+            |progression: &str| c_major.functions(&c_major.realize(progression).unwrap());
+        assert_eq!(
+            functions("I vi ii7 V7 I"),
+            [Tonic, Tonic, Predominant, Dominant, Tonic]
+        );
+        assert_eq!(
+            functions("I IV I64 V I"),
+            [Tonic, Predominant, Dominant, Dominant, Tonic]
+        );
+        assert_eq!(
+            functions("iii V7/V N6 Ger+6 vii°7 bVI"),
+            [
+                Tonic,
+                Dominant,
+                Predominant,
+                Predominant,
+                Dominant,
+                Predominant
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_authentic_cadences() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            cadences(&c_major, "I IV V7 I"),
+            [(CadenceKind::PerfectAuthentic, 3)]
+        );
+        assert_eq!(
+            cadences(&c_major, "I V6 I"),
+            [(CadenceKind::ImperfectAuthentic, 2)]
+        );
+        assert_eq!(
+            cadences(&c_major, "I vii° I6"),
+            [(CadenceKind::ImperfectAuthentic, 2)]
+        );
+        assert_eq!(
+            cadences(&key("A", KeyMode::Minor), "i iv V i"),
+            [(CadenceKind::PerfectAuthentic, 3)]
+        );
+    }
+
+    #[test]
+    fn detects_half_cadences() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(cadences(&c_major, "I vi ii V"), [(CadenceKind::Half, 3)]);
+        assert_eq!(cadences(&c_major, "I IV V IV"), [(CadenceKind::Half, 2)]);
+        assert!(cadences(&c_major, "I ii V V7").is_empty());
+        assert_eq!(
+            cadences(&key("A", KeyMode::Minor), "i iv6 V"),
+            [(CadenceKind::PhrygianHalf, 2)]
+        );
+    }
+
+    #[test]
+    fn detects_plagal_and_deceptive_cadences() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(cadences(&c_major, "I IV I"), [(CadenceKind::Plagal, 2)]);
+        assert_eq!(cadences(&c_major, "I iv I"), [(CadenceKind::Plagal, 2)]);
+        assert_eq!(cadences(&c_major, "I V7 vi"), [(CadenceKind::Deceptive, 2)]);
+        assert_eq!(
+            cadences(&c_major, "I V7 bVI"),
+            [(CadenceKind::Deceptive, 2)]
+        );
+        assert_eq!(
+            cadences(&key("A", KeyMode::Minor), "i V VI"),
+            [(CadenceKind::Deceptive, 2)]
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::chord::{Chord, ChordQuality, ChordSeventh};
+use crate::note::Note;
+use crate::scale::{Scale, ScaleDirection, ScaleKind};
+
+use super::{AugmentedSixthKind, RomanNumeral};
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum KeyMode {
+    Major,
+    Minor,
+}
+
+impl Display for KeyMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyMode::Major => write!(f, "major"),
+            KeyMode::Minor => write!(f, "minor"),
+        }
+    }
+}
+
+// a tonal center that chords can be analyzed against.
+// the degrees of a minor key come from the natural minor scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Key {
+    tonic: Note,
+    mode: KeyMode,
+}
+
+impl Key {
+    pub fn new(tonic: Note, mode: KeyMode) -> Self {
+        Self { tonic, mode }
+    }
+
+    pub fn tonic(&self) -> Note {
+        self.tonic
+    }
+
+    pub fn mode(&self) -> KeyMode {
+        self.mode
+    }
+
+    pub fn scale(&self) -> Scale {
+        let kind = match self.mode {
+            KeyMode::Major => ScaleKind::Major,
+            KeyMode::Minor => ScaleKind::Minor,
+        };
+        Scale::new(self.tonic, kind, ScaleDirection::Ascending)
+    }
+
+    // the note on the given scale degree, where 1 is the tonic. degrees above 7 wrap around, and 0 is None.
+    pub fn degree(&self, degree: u8) -> Option<Note> {
+        self.scale().degree(degree as usize)
+    }
+
+    // the triad built from the key's own notes on the given scale degree, or None for degree 0
+    pub fn triad(&self, degree: u8) -> Option<Chord> {
+        let index = (degree as usize).checked_sub(1)? % 7;
+        let triads = self.scale().triads().unwrap();
+        let triad = triads[index]
+            .clone()
+            .expect("every degree of a major or minor scale has a triad");
+        Some(triad)
+    }
+
+    // whether every note of the chord belongs to the key.
+    // the raised seventh degree of the harmonic minor scale counts as part of a minor key.
+    pub fn is_diatonic(&self, chord: &Chord) -> bool {
+        let mut notes = self.scale().notes();
+        if self.mode == KeyMode::Minor {
+            notes.push(notes[6].altered(1).unwrap_or(notes[6]));
+        }
+        chord.notes().iter().all(|note| notes.contains(note))
+    }
+
+    // whether the chord is borrowed from the parallel key, e.g. Fm (iv) or Ab (bVI) in C major
+    pub fn is_borrowed(&self, chord: &Chord) -> bool {
+        !self.is_diatonic(chord) && self.parallel().is_diatonic(chord)
+    }
+
+    // the triads of the parallel key that don't belong to this key, e.g. Cm, Ddim, Eb, Fm, Gm, Ab and Bb in C major
+    pub fn borrowed_chords(&self) -> Vec<Chord> {
+        self.parallel()
+            .scale()
+            .triads()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .filter(|chord| !self.is_diatonic(chord))
+            .collect()
+    }
+
+    // the dominant seventh chord of the given degree, e.g. D7 (V7/V) for degree 5 in C major
+    pub fn secondary_dominant(&self, degree: u8) -> Result<Chord, String> {
+        self.applied_chord(
+            RomanNumeral::new(5, ChordQuality::Major).with_seventh(ChordSeventh::Minor),
+            degree,
+        )
+    }
+
+    // the diminished seventh chord leading to the given degree, e.g. F#dim7 (vii°7/V) for degree 5 in C major
+    pub fn secondary_leading_tone(&self, degree: u8) -> Result<Chord, String> {
+        self.applied_chord(
+            RomanNumeral::new(7, ChordQuality::Diminished).with_seventh(ChordSeventh::Diminished),
+            degree,
+        )
+    }
+
+    fn applied_chord(&self, numeral: RomanNumeral, degree: u8) -> Result<Chord, String> {
+        if !(1..=7).contains(&degree) {
+            return Err(format!(
+                "{degree} is not a scale degree. Only degrees 1-7 are supported"
+            ));
+        }
+        let target = self.analyze(&self.triad(degree).unwrap());
+        if target.quality() == ChordQuality::Diminished {
+            return Err(format!(
+                "The diminished triad on degree {degree} of {self} can't be tonicized"
+            ));
+        }
+        numeral.applied_to(target).to_chord(self)
+    }
+
+    // the Neapolitan sixth chord, e.g. Db/F in C
+    pub fn neapolitan(&self) -> Chord {
+        RomanNumeral::new_neapolitan().to_chord(self).unwrap()
+    }
+
+    // the augmented sixth chord of the given kind, e.g. Ab C Eb F# for the German sixth in C
+    pub fn augmented_sixth(&self, kind: AugmentedSixthKind) -> Chord {
+        RomanNumeral::new_augmented_sixth(kind)
+            .to_chord(self)
+            .unwrap()
+    }
+
+    // the major or minor key sharing this key's tonic
+    pub fn parallel(&self) -> Key {
+        match self.mode {
+            KeyMode::Major => Key::new(self.tonic, KeyMode::Minor),
+            KeyMode::Minor => Key::new(self.tonic, KeyMode::Major),
+        }
+    }
+
+    // the roman numeral of the chord in this key, e.g. Dm7/C in C major is ii42
+    pub fn analyze(&self, chord: &Chord) -> RomanNumeral {
+        RomanNumeral::analyze(self, chord)
+    }
+
+    // realizes a space separated progression of roman numerals as chords in this key, e.g. "ii7 V7 I"
+    pub fn realize(&self, progression: &str) -> Result<Vec<Chord>, String> {
+        progression
+            .split_whitespace()
+            .map(|numeral| RomanNumeral::try_from(numeral)?.to_chord(self))
+            .collect()
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.tonic, self.mode)
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod key_test {
+    use super::*;
+
+    fn key(tonic: &str, mode: KeyMode) -> Key {
+        Key::new(Note::try_from(tonic).unwrap(), mode)
+    }
+
+    fn names(chords: &[Chord]) -> Vec<String> {
+        chords.iter().map(|chord| chord.name()).collect()
+    }
+
+    #[test]
+    fn borrows_chords_from_parallel_key() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            names(&c_major.borrowed_chords()),
+            ["Cm", "Ddim", "Eb", "Fm", "Gm", "Ab", "Bb"]
+        );
+        assert_eq!(
+            names(&key("A", KeyMode::Minor).borrowed_chords()),
+            ["A", "Bm", "C#m", "D", "F#m"]
+        );
+
+        let f_minor = Chord::new(Note::try_from("F").unwrap(), ChordQuality::Minor);
+        let d_minor = Chord::new(Note::try_from("D").unwrap(), ChordQuality::Minor);
+        assert!(c_major.is_borrowed(&f_minor));
+        assert!(!c_major.is_borrowed(&d_minor));
+    }
+
+    #[test]
+    fn minor_keys_include_the_leading_tone() {
+        let e7 = Chord::new(Note::try_from("E").unwrap(), ChordQuality::Major)
+            .with_seventh(ChordSeventh::Minor);
+        assert!(key("A", KeyMode::Minor).is_diatonic(&e7));
+        assert!(!key("C", KeyMode::Major).is_diatonic(&e7));
+    }
+
+    #[test]
+    fn builds_secondary_dominants_and_leading_tone_chords() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(c_major.secondary_dominant(5).unwrap().name(), "D7");
+        assert_eq!(c_major.secondary_dominant(2).unwrap().name(), "A7");
+        assert_eq!(c_major.secondary_dominant(1).unwrap().name(), "G7");
+        assert!(c_major.secondary_dominant(7).is_err());
+        assert!(c_major.secondary_dominant(8).is_err());
+        assert_eq!(c_major.secondary_leading_tone(5).unwrap().name(), "F#dim7");
+
+        let bb_major = key("Bb", KeyMode::Major);
+        assert_eq!(bb_major.secondary_dominant(6).unwrap().name(), "D7");
+        assert_eq!(bb_major.secondary_leading_tone(2).unwrap().name(), "Bdim7");
+    }
+
+    #[test]
+    fn builds_neapolitan_and_augmented_sixth_chords() {
+        assert_eq!(key("C", KeyMode::Major).neapolitan().name(), "Db/F");
+        assert_eq!(key("E", KeyMode::Minor).neapolitan().name(), "F/A");
+
+        let german = key("E", KeyMode::Minor).augmented_sixth(AugmentedSixthKind::German);
+        let spelled: Vec<String> = german.notes().iter().map(|note| note.to_string()).collect();
+        assert_eq!(spelled, ["C", "E", "G", "A#"]);
+    }
+
+    #[test]
+    fn finds_notes_and_triads_on_degrees() {
+        let a_minor = key("A", KeyMode::Minor);
+        assert_eq!(a_minor.degree(3), Some(Note::try_from("C").unwrap()));
+        assert_eq!(a_minor.degree(9), Some(Note::try_from("B").unwrap()));
+        assert_eq!(a_minor.degree(0), None);
+        assert_eq!(a_minor.triad(2).unwrap().name(), "Bdim");
+        assert_eq!(a_minor.triad(0), None);
+    }
+}
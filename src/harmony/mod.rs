@@ -0,0 +1,17 @@
+mod key;
+pub use key::{Key, KeyMode};
+
+mod roman;
+pub use roman::{AugmentedSixthKind, RomanNumeral};
+
+mod chord_scale;
+pub use chord_scale::{ChordScale, ScaleTone, ScaleToneRole};
+
+mod reharmonization;
+pub use reharmonization::{Reharmonization, ReharmonizationRule};
+
+mod neo_riemannian;
+pub use neo_riemannian::NeoRiemannian;
+
+mod function;
+pub use function::{Cadence, CadenceKind, HarmonicFunction};
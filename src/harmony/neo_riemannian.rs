@@ -0,0 +1,324 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use crate::chord::{Chord, ChordQuality};
+use crate::note::{Note, NotePitchInterval};
+
+// the neo-Riemannian transformations of major and minor triads.
+// the basic ones keep two tones and move the third by step:
+// P swaps C and Cm, L swaps C and Em, and R swaps C and Am.
+// the compound ones chain them: N is RLP (C to Fm), S is LPR (C to C#m) and H is LPL (C to G#m).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NeoRiemannian {
+    Parallel,
+    LeadingToneExchange,
+    Relative,
+    Nebenverwandt,
+    Slide,
+    HexatonicPole,
+}
+
+impl NeoRiemannian {
+    // the P, L and R steps the transformation is made of
+    pub fn steps(&self) -> &'static [NeoRiemannian] {
+        use NeoRiemannian::*;
+        match self {
+            Parallel => &[Parallel],
+            LeadingToneExchange => &[LeadingToneExchange],
+            Relative => &[Relative],
+            Nebenverwandt => &[Relative, LeadingToneExchange, Parallel],
+            Slide => &[LeadingToneExchange, Parallel, Relative],
+            HexatonicPole => &[LeadingToneExchange, Parallel, LeadingToneExchange],
+        }
+    }
+}
+
+impl Display for NeoRiemannian {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let letter = match self {
+            NeoRiemannian::Parallel => "P",
+            NeoRiemannian::LeadingToneExchange => "L",
+            NeoRiemannian::Relative => "R",
+            NeoRiemannian::Nebenverwandt => "N",
+            NeoRiemannian::Slide => "S",
+            NeoRiemannian::HexatonicPole => "H",
+        };
+        write!(f, "{letter}")
+    }
+}
+
+impl TryFrom<char> for NeoRiemannian {
+    type Error = String;
+
+    fn try_from(letter: char) -> Result<Self, Self::Error> {
+        match letter.to_ascii_uppercase() {
+            'P' => Ok(NeoRiemannian::Parallel),
+            'L' => Ok(NeoRiemannian::LeadingToneExchange),
+            'R' => Ok(NeoRiemannian::Relative),
+            'N' => Ok(NeoRiemannian::Nebenverwandt),
+            'S' => Ok(NeoRiemannian::Slide),
+            'H' => Ok(NeoRiemannian::HexatonicPole),
+            _ => Err(format!(
+                "{letter} is not a neo-Riemannian transformation. Only P, L, R, N, S and H are supported"
+            )),
+        }
+    }
+}
+
+impl Chord {
+    // applies the transformation to the root triad of a major or minor chord.
+    // the result is spelled by moving the root, so the common tones keep their spelling, e.g. S turns Cm into Cb.
+    pub fn transform(&self, transformation: NeoRiemannian) -> Result<Chord, String> {
+        let (mut root, mut quality) = triad(self)?;
+        for step in transformation.steps() {
+            (root, quality) = step_triad(root, quality, *step)?;
+        }
+        Ok(Chord::new(root, quality))
+    }
+
+    // applies the transformations from left to right, e.g. "PLR" or "NS"
+    pub fn transform_all(&self, transformations: &str) -> Result<Chord, String> {
+        let (root, quality) = triad(self)?;
+        let mut result = Chord::new(root, quality);
+        for letter in transformations.chars().filter(|c| !c.is_whitespace()) {
+            result = result.transform(NeoRiemannian::try_from(letter)?)?;
+        }
+        Ok(result)
+    }
+
+    // the fewest P, L and R steps leading from this triad to the other one, found by breadth first search.
+    // enharmonic triads count as the same, so C to G#m and C to Abm are both PLP.
+    pub fn shortest_transformation_path(
+        &self,
+        other: &Chord,
+    ) -> Result<Vec<NeoRiemannian>, String> {
+        use NeoRiemannian::*;
+
+        let start = triad_pitch_class(self)?;
+        let goal = triad_pitch_class(other)?;
+
+        let mut previous: HashMap<(u8, ChordQuality), ((u8, ChordQuality), NeoRiemannian)> =
+            HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                let mut path = vec![];
+                let mut position = current;
+                while let Some(&(from, step)) = previous.get(&position) {
+                    path.push(step);
+                    position = from;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for step in [Parallel, LeadingToneExchange, Relative] {
+                let next = step_pitch_class(current, step);
+                if next != start && !previous.contains_key(&next) {
+                    previous.insert(next, (current, step));
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(format!(
+            "There's no path from {} to {}",
+            self.name(),
+            other.name()
+        ))
+    }
+
+    // the positions of the root, third and fifth of a major or minor triad on the Tonnetz,
+    // where x counts perfect fifths and y major thirds.
+    // the root lies in the region 0 <= x < 4, 0 <= y < 3, which holds every pitch class once.
+    // major triads point up, with the third above the root, and minor triads point down.
+    pub fn tonnetz(&self) -> Result<[(i8, i8); 3], String> {
+        let (root, quality) = triad(self)?;
+        let (x, y) = tonnetz_position(root);
+        let third = match quality {
+            ChordQuality::Major => (x, y + 1),
+            _ => (x + 1, y - 1),
+        };
+        Ok([(x, y), third, (x + 1, y)])
+    }
+}
+
+// the root and quality of a major or minor chord
+fn triad(chord: &Chord) -> Result<(Note, ChordQuality), String> {
+    match chord.quality() {
+        ChordQuality::Major | ChordQuality::Minor => Ok((chord.root(), chord.quality())),
+        _ => Err(format!(
+            "{} is not a major or minor chord. Only major and minor triads can be transformed",
+            chord.name()
+        )),
+    }
+}
+
+// the triad one step away, or an error when it would need more than two accidentals, e.g. P on Fbb
+fn step_triad(
+    root: Note,
+    quality: ChordQuality,
+    step: NeoRiemannian,
+) -> Result<(Note, ChordQuality), String> {
+    use NotePitchInterval::*;
+
+    let is_major = quality == ChordQuality::Major;
+    let flipped = match quality {
+        ChordQuality::Major => ChordQuality::Minor,
+        _ => ChordQuality::Major,
+    };
+    let stepped = match step {
+        NeoRiemannian::LeadingToneExchange if is_major => {
+            root.checked_by_interval_ascending(MajorThird)
+        }
+        NeoRiemannian::LeadingToneExchange => {
+            root.checked_by_interval_ascending(MajorThird.invert())
+        }
+        NeoRiemannian::Relative if is_major => {
+            root.checked_by_interval_ascending(MinorThird.invert())
+        }
+        NeoRiemannian::Relative => root.checked_by_interval_ascending(MinorThird),
+        _ => Some(root),
+    };
+    stepped
+        .filter(|&root| Chord::new(root, flipped).notes_checked().is_some())
+        .map(|root| (root, flipped))
+        .ok_or(format!(
+            "{step} on {} can't be spelled with at most two accidentals",
+            Chord::new(root, quality).name()
+        ))
+}
+
+// the same steps on the pitch class of the root, so long searches don't run out of accidentals
+fn triad_pitch_class(chord: &Chord) -> Result<(u8, ChordQuality), String> {
+    let (root, quality) = triad(chord)?;
+    Ok((root.pitch_class(), quality))
+}
+
+fn step_pitch_class(
+    (root, quality): (u8, ChordQuality),
+    step: NeoRiemannian,
+) -> (u8, ChordQuality) {
+    let is_major = quality == ChordQuality::Major;
+    let flipped = match quality {
+        ChordQuality::Major => ChordQuality::Minor,
+        _ => ChordQuality::Major,
+    };
+    let semitones = match step {
+        NeoRiemannian::LeadingToneExchange if is_major => 4,
+        NeoRiemannian::LeadingToneExchange => 8,
+        NeoRiemannian::Relative if is_major => 9,
+        NeoRiemannian::Relative => 3,
+        _ => 0,
+    };
+    ((root + semitones) % 12, flipped)
+}
+
+fn tonnetz_position(note: Note) -> (i8, i8) {
+    let pitch_class = note.pitch_class() as i8;
+    for x in 0..4 {
+        for y in 0..3 {
+            if (7 * x + 4 * y) % 12 == pitch_class {
+                return (x, y);
+            }
+        }
+    }
+    unreachable!("every pitch class has a position on the Tonnetz")
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod neo_riemannian_test {
+    use super::*;
+    use NeoRiemannian::*;
+
+    fn major(root: &str) -> Chord {
+        Chord::new(Note::try_from(root).unwrap(), ChordQuality::Major)
+    }
+
+    fn minor(root: &str) -> Chord {
+        Chord::new(Note::try_from(root).unwrap(), ChordQuality::Minor)
+    }
+
+    fn transformed(chord: &Chord, transformation: NeoRiemannian) -> String {
+        chord.transform(transformation).unwrap().name().to_string()
+    }
+
+    #[test]
+    fn basic_transformations_keep_two_tones() {
+        assert_eq!(transformed(&major("C"), Parallel), "Cm");
+        assert_eq!(transformed(&major("C"), LeadingToneExchange), "Em");
+        assert_eq!(transformed(&major("C"), Relative), "Am");
+        assert_eq!(transformed(&minor("C"), Parallel), "C");
+        assert_eq!(transformed(&minor("C"), LeadingToneExchange), "Ab");
+        assert_eq!(transformed(&minor("C"), Relative), "Eb");
+        assert_eq!(transformed(&minor("F#"), Relative), "A");
+    }
+
+    #[test]
+    fn basic_transformations_are_involutions() {
+        for chord in [major("C"), minor("C#"), major("Bb"), minor("G")] {
+            for transformation in [Parallel, LeadingToneExchange, Relative] {
+                let back = chord
+                    .transform(transformation)
+                    .unwrap()
+                    .transform(transformation)
+                    .unwrap();
+                assert_eq!(back, chord);
+            }
+        }
+    }
+
+    #[test]
+    fn compound_transformations() {
+        assert_eq!(transformed(&major("C"), Nebenverwandt), "Fm");
+        assert_eq!(transformed(&minor("C"), Nebenverwandt), "G");
+        assert_eq!(transformed(&major("C"), Slide), "C#m");
+        assert_eq!(transformed(&minor("C"), Slide), "Cb");
+        assert_eq!(transformed(&major("C"), HexatonicPole), "G#m");
+        assert_eq!(major("C").transform_all("PL").unwrap().name(), "Ab");
+        assert_eq!(major("C").transform_all("plr").unwrap().name(), "Fm");
+        assert!(major("C").transform_all("PX").is_err());
+    }
+
+    #[test]
+    fn fails_when_accidentals_run_out() {
+        let far = major("C").transform_all(&"PR".repeat(5)).unwrap();
+        assert_eq!(far.name(), "Fbb");
+        assert!(far.transform(Parallel).is_err());
+        assert!(major("C").transform_all(&"PR".repeat(11)).is_err());
+    }
+
+    #[test]
+    fn transforms_only_major_and_minor_chords() {
+        let diminished = Chord::new(Note::try_from("B").unwrap(), ChordQuality::Diminished);
+        assert!(diminished.transform(Parallel).is_err());
+        assert!(diminished.tonnetz().is_err());
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let path = |from: &Chord, to: &Chord| {
+            from.shortest_transformation_path(to)
+                .unwrap()
+                .iter()
+                .map(|step| step.to_string())
+                .collect::<String>()
+        };
+        assert_eq!(path(&major("C"), &major("C")), "");
+        assert_eq!(path(&major("C"), &minor("A")), "R");
+        assert_eq!(path(&major("C"), &minor("F")), "PLR");
+        assert_eq!(path(&major("C"), &minor("G#")), "PLP");
+        assert_eq!(path(&major("C"), &minor("Ab")), "PLP");
+        assert_eq!(path(&major("C"), &major("F#")), "PRPR");
+    }
+
+    #[test]
+    fn places_triads_on_the_tonnetz() {
+        assert_eq!(major("C").tonnetz().unwrap(), [(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(minor("C").tonnetz().unwrap(), [(0, 0), (1, -1), (1, 0)]);
+        assert_eq!(major("G").tonnetz().unwrap()[0], (1, 0));
+        assert_eq!(major("E").tonnetz().unwrap()[0], (0, 1));
+    }
+}
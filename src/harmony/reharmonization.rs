@@ -0,0 +1,362 @@
+use std::fmt::{Display, Formatter};
+
+use crate::chord::{Chord, ChordQuality, ChordSeventh};
+use crate::note::{Note, NotePitchInterval};
+
+// the ways a progression can be reharmonized
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReharmonizationRule {
+    // replaces a dominant with the dominant a tritone away, e.g. G7 with Db7
+    TritoneSubstitution,
+    // approaches a chord with its own ii-V, or a dominant with its ii, e.g. Am7 with Bm7b5 E7
+    RelatedTwoFive,
+    // replaces a dominant resolving to a major chord with the dominant a whole step below the target, e.g. G7 C with Bb7 C
+    BackdoorDominant,
+    // connects chords whose roots are a whole step apart with a diminished seventh chord, e.g. C C#dim7 Dm
+    DiminishedPassingChord,
+    // swaps a major chord for its relative minor or a minor chord for its relative major, e.g. Cmaj7 with Am7
+    RelativeSwap,
+}
+
+impl Display for ReharmonizationRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReharmonizationRule::TritoneSubstitution => write!(f, "tritone substitution"),
+            ReharmonizationRule::RelatedTwoFive => write!(f, "related ii-V"),
+            ReharmonizationRule::BackdoorDominant => write!(f, "backdoor dominant"),
+            ReharmonizationRule::DiminishedPassingChord => write!(f, "diminished passing chord"),
+            ReharmonizationRule::RelativeSwap => write!(f, "relative major/minor swap"),
+        }
+    }
+}
+
+// one alternative to a progression: the chords replacing a stretch of the original, and the rule applied.
+// the replaced stretch starts at index and may be empty, as it is for inserted chords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reharmonization {
+    rule: ReharmonizationRule,
+    index: usize,
+    original: Vec<Chord>,
+    chords: Vec<Chord>,
+    progression: Vec<Chord>,
+}
+
+impl Reharmonization {
+    // every reharmonization the rules allow for the progression, ordered by position and then by rule
+    pub fn suggest(progression: &[Chord]) -> Vec<Reharmonization> {
+        let mut result: Vec<Reharmonization> = vec![];
+        let mut suggest = |rule, index, replaced, chords: Vec<Chord>| {
+            // chords with tones past two accidentals, like the tritone substitute of Ebb7, are left out
+            if chords.iter().any(|chord| chord.notes_checked().is_none()) {
+                return;
+            }
+            let mut reharmonized = progression.to_vec();
+            reharmonized.splice(index..index + replaced, chords.iter().cloned());
+            result.push(Reharmonization {
+                rule,
+                index,
+                original: progression[index..index + replaced].to_vec(),
+                chords,
+                progression: reharmonized,
+            });
+        };
+
+        for (index, chord) in progression.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|previous| &progression[previous]);
+            let next = progression.get(index + 1);
+
+            if let Some(substitute) = tritone_substitute(chord) {
+                suggest(
+                    ReharmonizationRule::TritoneSubstitution,
+                    index,
+                    1,
+                    vec![substitute],
+                );
+            }
+            if let Some(chords) = related_two_five(previous, chord, next) {
+                let replaced = if chord.is_dominant() { 1 } else { 0 };
+                suggest(ReharmonizationRule::RelatedTwoFive, index, replaced, chords);
+            }
+            if let Some(backdoor) = next.and_then(|next| backdoor_dominant(chord, next)) {
+                suggest(
+                    ReharmonizationRule::BackdoorDominant,
+                    index,
+                    1,
+                    vec![backdoor],
+                );
+            }
+            if let Some(relative) = relative_swap(chord) {
+                suggest(ReharmonizationRule::RelativeSwap, index, 1, vec![relative]);
+            }
+            if let Some(passing) = next.and_then(|next| diminished_passing_chord(chord, next)) {
+                suggest(
+                    ReharmonizationRule::DiminishedPassingChord,
+                    index + 1,
+                    0,
+                    vec![passing],
+                );
+            }
+        }
+        result
+    }
+
+    pub fn rule(&self) -> ReharmonizationRule {
+        self.rule
+    }
+    // where the replaced chords start in the original progression
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    // the chords of the original progression that are replaced
+    pub fn original(&self) -> &[Chord] {
+        &self.original
+    }
+    // the chords taking the place of the replaced ones
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+    // the whole progression after the reharmonization
+    pub fn progression(&self) -> &[Chord] {
+        &self.progression
+    }
+}
+
+impl Display for Reharmonization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = |chords: &[Chord]| {
+            chords
+                .iter()
+                .map(|chord| chord.name())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        match self.original.is_empty() {
+            true => write!(f, "{}: insert {}", self.rule, names(&self.chords)),
+            false => write!(
+                f,
+                "{}: {} -> {}",
+                self.rule,
+                names(&self.original),
+                names(&self.chords)
+            ),
+        }
+    }
+}
+
+fn dominant_seventh(root: Note) -> Chord {
+    Chord::new(root, ChordQuality::Major).with_seventh(ChordSeventh::Minor)
+}
+
+fn tritone_substitute(dominant: &Chord) -> Option<Chord> {
+    if !dominant.is_dominant() {
+        return None;
+    }
+    let root = dominant
+        .root()
+        .checked_by_interval_ascending(NotePitchInterval::DiminishedFifth)?;
+    Some(dominant_seventh(root))
+}
+
+// the ii of a dominant that isn't already preceded by it, or the ii-V of any other major or minor chord
+// that isn't already preceded by its dominant. targets that are minor get a half-diminished ii.
+fn related_two_five(
+    previous: Option<&Chord>,
+    chord: &Chord,
+    next: Option<&Chord>,
+) -> Option<Vec<Chord>> {
+    use NotePitchInterval::*;
+
+    let two = |dominant_root: Note, minor_target: bool| {
+        let root = dominant_root.checked_by_interval_ascending(PerfectFifth)?;
+        let two = match minor_target {
+            true => Chord::new(root, ChordQuality::Diminished).with_seventh(ChordSeventh::Minor),
+            false => Chord::new(root, ChordQuality::Minor).with_seventh(ChordSeventh::Minor),
+        };
+        Some(two)
+    };
+    let is_minor = |chord: &Chord| chord.quality() == ChordQuality::Minor;
+
+    if chord.is_dominant() {
+        let two = two(chord.root(), next.is_some_and(is_minor))?;
+        if previous.is_some_and(|previous| previous.root() == two.root()) {
+            return None;
+        }
+        return Some(vec![two, chord.clone()]);
+    }
+    if !matches!(chord.quality(), ChordQuality::Major | ChordQuality::Minor) {
+        return None;
+    }
+    let five = chord.root().checked_by_interval_ascending(PerfectFifth)?;
+    if previous.is_some_and(|previous| previous.root() == five) {
+        return None;
+    }
+    Some(vec![two(five, is_minor(chord))?, dominant_seventh(five)])
+}
+
+// the dominant a whole step below a major chord, replacing the dominant a fifth above it
+fn backdoor_dominant(chord: &Chord, next: &Chord) -> Option<Chord> {
+    use NotePitchInterval::*;
+
+    let resolves = chord.is_dominant()
+        && next.quality() == ChordQuality::Major
+        && chord.root().checked_by_interval_ascending(PerfectFourth) == Some(next.root());
+    if !resolves {
+        return None;
+    }
+    let root = next
+        .root()
+        .checked_by_interval_ascending(MajorSecond.invert())?;
+    Some(dominant_seventh(root))
+}
+
+// the diminished seventh chord on the raised root of the first chord, leading to a chord a whole step above it
+fn diminished_passing_chord(chord: &Chord, next: &Chord) -> Option<Chord> {
+    if chord.root().interval_to(&next.root()) != Some(NotePitchInterval::MajorSecond) {
+        return None;
+    }
+    let root = chord.root().altered(1)?;
+    Some(Chord::new(root, ChordQuality::Diminished).with_seventh(ChordSeventh::Diminished))
+}
+
+// the relative minor of a major triad or major seventh chord, or the relative major of a minor one
+fn relative_swap(chord: &Chord) -> Option<Chord> {
+    use NotePitchInterval::*;
+
+    let seventh = chord.seventh_interval();
+    match (chord.quality(), seventh) {
+        (ChordQuality::Major, None | Some(MajorSeventh)) => {
+            let root = chord
+                .root()
+                .checked_by_interval_ascending(MinorThird.invert())?;
+            let relative = Chord::new(root, ChordQuality::Minor);
+            Some(match seventh {
+                Some(_) => relative.with_seventh(ChordSeventh::Minor),
+                None => relative,
+            })
+        }
+        (ChordQuality::Minor, None | Some(MinorSeventh)) => {
+            let root = chord.root().checked_by_interval_ascending(MinorThird)?;
+            let relative = Chord::new(root, ChordQuality::Major);
+            Some(match seventh {
+                Some(_) => relative.with_seventh(ChordSeventh::Major),
+                None => relative,
+            })
+        }
+        _ => None,
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod reharmonization_test {
+    use super::*;
+
+    fn chord(name: &str) -> Chord {
+        let root = Note::try_from(&name[..1]).unwrap();
+        match &name[1..] {
+            "" => Chord::new(root, ChordQuality::Major),
+            "m" => Chord::new(root, ChordQuality::Minor),
+            "7" => dominant_seventh(root),
+            "m7" => Chord::new(root, ChordQuality::Minor).with_seventh(ChordSeventh::Minor),
+            _ => Chord::new(root, ChordQuality::Major).with_seventh(ChordSeventh::Major),
+        }
+    }
+
+    fn progression(names: &str) -> Vec<Chord> {
+        names.split(' ').map(chord).collect()
+    }
+
+    fn described(reharmonizations: &[Reharmonization]) -> Vec<String> {
+        reharmonizations
+            .iter()
+            .map(|reharmonization| reharmonization.to_string())
+            .collect()
+    }
+
+    fn names(chords: &[Chord]) -> String {
+        chords
+            .iter()
+            .map(|chord| chord.name())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn suggests_every_rule_in_order() {
+        let suggestions = Reharmonization::suggest(&progression("Dm7 G7 Cmaj7 Am7"));
+        assert_eq!(
+            described(&suggestions),
+            [
+                "related ii-V: insert Em7b5 A7",
+                "relative major/minor swap: Dm7 -> Fmaj7",
+                "tritone substitution: G7 -> Db7",
+                "backdoor dominant: G7 -> Bb7",
+                "relative major/minor swap: Cmaj7 -> Am7",
+                "related ii-V: insert Bm7b5 E7",
+                "relative major/minor swap: Am7 -> Cmaj7",
+            ]
+        );
+        assert_eq!(names(suggestions[2].progression()), "Dm7 Db7 Cmaj7 Am7");
+        assert_eq!(
+            names(suggestions[5].progression()),
+            "Dm7 G7 Cmaj7 Bm7b5 E7 Am7"
+        );
+        assert_eq!(suggestions[5].index(), 3);
+        assert!(suggestions[5].original().is_empty());
+    }
+
+    #[test]
+    fn adds_the_missing_two_of_a_dominant() {
+        let suggestions = Reharmonization::suggest(&progression("C A7 Dm"));
+        let two_five: Vec<&Reharmonization> = suggestions
+            .iter()
+            .filter(|suggestion| suggestion.rule() == ReharmonizationRule::RelatedTwoFive)
+            .collect();
+        assert_eq!(two_five.len(), 2, "Dm is already preceded by its dominant");
+        assert_eq!(two_five[0].to_string(), "related ii-V: insert Dm7 G7");
+        assert_eq!(two_five[1].to_string(), "related ii-V: A7 -> Em7b5 A7");
+        assert_eq!(names(two_five[1].progression()), "C Em7b5 A7 Dm");
+    }
+
+    #[test]
+    fn connects_whole_steps_with_diminished_chords() {
+        let suggestions: Vec<Reharmonization> = Reharmonization::suggest(&progression("C Dm Em F"))
+            .into_iter()
+            .filter(|suggestion| suggestion.rule() == ReharmonizationRule::DiminishedPassingChord)
+            .collect();
+        assert_eq!(
+            described(&suggestions),
+            [
+                "diminished passing chord: insert C#dim7",
+                "diminished passing chord: insert D#dim7"
+            ]
+        );
+        assert_eq!(names(suggestions[0].progression()), "C C#dim7 Dm Em F");
+    }
+
+    #[test]
+    fn backdoor_dominant_needs_a_major_target() {
+        let suggestions = Reharmonization::suggest(&progression("E7 Am"));
+        assert!(suggestions
+            .iter()
+            .all(|suggestion| suggestion.rule() != ReharmonizationRule::BackdoorDominant));
+    }
+
+    #[test]
+    fn skips_chords_past_two_accidentals() {
+        let e_double_flat_7 = dominant_seventh(Note::try_from("Ebb").unwrap());
+        let a_double_flat = Chord::new(Note::try_from("Abb").unwrap(), ChordQuality::Major);
+        let suggestions = Reharmonization::suggest(&[e_double_flat_7, a_double_flat]);
+        assert!(!suggestions.is_empty());
+        assert!(suggestions
+            .iter()
+            .all(|suggestion| suggestion.rule() != ReharmonizationRule::TritoneSubstitution));
+        assert!(suggestions
+            .iter()
+            .flat_map(|suggestion| suggestion.chords())
+            .all(|chord| chord.notes_checked().is_some()));
+    }
+}
@@ -0,0 +1,774 @@
+use std::fmt::{Display, Formatter};
+
+use crate::chord::{AccidentalGlyphs, Chord, ChordExtensionKind, ChordQuality, ChordSeventh};
+use crate::note::{Note, NotePitchInterval};
+
+use super::{Key, KeyMode};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+// global static regex to parse a roman numeral that's only compiled once.
+// unicode accidentals and figures are turned into their ascii counterparts before matching.
+lazy_static! {
+  static ref ROMAN_NUMERAL_REGEX: Regex = Regex::new(
+    r"^(?P<accidental>bb|b|##|#)?(?P<numeral>VII|VI|IV|V|III|II|I|vii|vi|iv|v|iii|ii|i|N)(?P<quality>o|°|ø|\+)?(?P<major>M|maj)?(?P<figures>65|64|43|42|2|6|7|9|11|13)?(?P<suspension>sus2|sus4)?(?:/(?P<target>.+))?$"
+  ).unwrap();
+  static ref AUGMENTED_SIXTH_REGEX: Regex =
+    Regex::new(r"^(?P<kind>It|Fr|Ger)\+?6(?:/(?P<target>.+))?$").unwrap();
+}
+
+// the augmented sixth chords, all built above the lowered sixth degree with the raised fourth degree
+// an augmented sixth above it. in C they are Ab C F# (Italian), Ab C D F# (French) and Ab C Eb F# (German).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AugmentedSixthKind {
+    Italian,
+    French,
+    German,
+}
+
+impl AugmentedSixthKind {
+    // the kind of augmented sixth chord the chord is in the key, if it is one
+    fn of(key: &Key, chord: &Chord) -> Option<Self> {
+        use NotePitchInterval::*;
+
+        let intervals = chord.intervals();
+        if chord.root() != lowered_sixth(key)
+            || chord.quality() != ChordQuality::Major
+            || !intervals.contains(&AugmentedSixth)
+        {
+            return None;
+        }
+        if intervals.contains(&AugmentedFourth) {
+            Some(AugmentedSixthKind::French)
+        } else if intervals.contains(&PerfectFifth) {
+            Some(AugmentedSixthKind::German)
+        } else {
+            Some(AugmentedSixthKind::Italian)
+        }
+    }
+
+    fn to_chord(self, key: &Key) -> Chord {
+        use NotePitchInterval::*;
+
+        let chord = Chord::new(lowered_sixth(key), ChordQuality::Major);
+        let chord = match self {
+            AugmentedSixthKind::Italian => chord.without_fifth(),
+            AugmentedSixthKind::French => chord.without_fifth().with_added_tone(AugmentedFourth),
+            AugmentedSixthKind::German => chord,
+        };
+        chord.with_added_tone(AugmentedSixth)
+    }
+}
+
+impl Display for AugmentedSixthKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AugmentedSixthKind::Italian => write!(f, "It"),
+            AugmentedSixthKind::French => write!(f, "Fr"),
+            AugmentedSixthKind::German => write!(f, "Ger"),
+        }
+    }
+}
+
+// the sixth degree of the minor key on the same tonic, e.g. Ab in both C major and C minor
+fn lowered_sixth(key: &Key) -> Note {
+    Key::new(key.tonic(), KeyMode::Minor).degree(6).unwrap()
+}
+
+// a chord described by the scale degree of its root within a key, e.g. ii65 or V7/V.
+// uppercase numerals are major or augmented, lowercase ones minor or diminished,
+// and an accidental marks a root outside of the key, e.g. bVII.
+// in minor keys the seventh degree is raised for diminished chords, so vii° in A minor is G#°.
+// the Neapolitan (bII) is written N, e.g. N6, and augmented sixth chords by their kind, e.g. Ger+6.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomanNumeral {
+    degree: u8,
+    accidental: i8,
+    quality: ChordQuality,
+    seventh: Option<ChordSeventh>,
+    extension: ChordExtensionKind,
+    inversion: u8,
+    augmented_sixth: Option<AugmentedSixthKind>,
+    // the chord this one is applied to, e.g. the V of V/V
+    target: Option<Box<RomanNumeral>>,
+}
+
+impl RomanNumeral {
+    // a triad on the given degree, where 1 is the tonic
+    pub fn new(degree: u8, quality: ChordQuality) -> Self {
+        Self {
+            degree: ((degree as u16 + 6) % 7 + 1) as u8,
+            accidental: 0,
+            quality,
+            seventh: None,
+            extension: ChordExtensionKind::Triad,
+            inversion: 0,
+            augmented_sixth: None,
+            target: None,
+        }
+    }
+
+    // the major triad on the lowered second degree in first inversion, e.g. Db/F in C
+    pub fn new_neapolitan() -> Self {
+        RomanNumeral::new(2, ChordQuality::Major)
+            .with_accidental(-1)
+            .with_inversion(1)
+    }
+
+    // the augmented sixth chord of the given kind, with the lowered sixth degree in the bass
+    pub fn new_augmented_sixth(kind: AugmentedSixthKind) -> Self {
+        let mut result = RomanNumeral::new(6, ChordQuality::Major);
+        result.augmented_sixth = Some(kind);
+        result
+    }
+
+    // raises (or lowers, for negative numbers) the root by the given semitones, e.g. -1 for bVII
+    pub fn with_accidental(mut self, accidental: i8) -> Self {
+        self.accidental = accidental;
+        self
+    }
+
+    pub fn with_seventh(mut self, seventh: ChordSeventh) -> Self {
+        self.seventh = Some(seventh);
+        if self.extension == ChordExtensionKind::Triad {
+            self.extension = ChordExtensionKind::Seventh;
+        }
+        self
+    }
+
+    // stacks natural extensions up to the given kind, adding a minor seventh if there is no seventh yet
+    pub fn with_extension(mut self, extension: ChordExtensionKind) -> Self {
+        if extension != ChordExtensionKind::Triad && self.seventh.is_none() {
+            self.seventh = Some(ChordSeventh::Minor);
+        }
+        self.extension = extension;
+        self
+    }
+
+    pub fn with_inversion(mut self, inversion: u8) -> Self {
+        self.inversion = inversion;
+        self
+    }
+
+    // makes this an applied chord of the given target, e.g. V applied to V is V/V
+    pub fn applied_to(mut self, target: RomanNumeral) -> Self {
+        self.target = Some(Box::new(target));
+        self
+    }
+
+    // the roman numeral of the chord in the key.
+    // the highest natural extension is kept, but alterations aren't part of the numeral.
+    // chords outside the key are read as augmented sixths, then as applied dominant or leading-tone chords
+    // of a diatonic degree (V/V, vii°7/ii), and otherwise by their chromatic root, e.g. bVI or N6.
+    pub fn analyze(key: &Key, chord: &Chord) -> Self {
+        if let Some(kind) = AugmentedSixthKind::of(key, chord) {
+            return RomanNumeral::new_augmented_sixth(kind);
+        }
+        if !key.is_diatonic(chord) {
+            if let Some(applied) = RomanNumeral::analyze_applied(key, chord) {
+                return applied;
+            }
+        }
+
+        let root = chord.root();
+        let degree = key.tonic().name().steps_to(root.name()) + 1;
+        let diatonic = key
+            .degree(degree)
+            .expect("the letters from the tonic give a degree from 1 to 7");
+        let mut accidental = diatonic.semitones_to(&root) as i8;
+        if accidental > 6 {
+            accidental -= 12;
+        }
+        if key.mode() == KeyMode::Minor
+            && degree == 7
+            && accidental == 1
+            && chord.quality() == ChordQuality::Diminished
+        {
+            accidental = 0;
+        }
+
+        RomanNumeral::new(degree, chord.quality())
+            .with_accidental(accidental)
+            .with_chord_tones(chord)
+    }
+
+    // reads the chord as the dominant or leading-tone chord of a diatonic degree, e.g. D7 in C is V7/V.
+    // the tonic and diminished triads can't be tonicized.
+    fn analyze_applied(key: &Key, chord: &Chord) -> Option<Self> {
+        use ChordQuality::*;
+        use NotePitchInterval::*;
+
+        let (degree, interval) = match (chord.quality(), chord.seventh_interval()) {
+            (Major, None | Some(MinorSeventh)) => (5, PerfectFourth),
+            (Diminished, None | Some(MinorSeventh | DiminishedSeventh)) => (7, MinorSecond),
+            _ => return None,
+        };
+        let target = chord.root().checked_by_interval_ascending(interval)?;
+        let target_degree = key.tonic().name().steps_to(target.name()) + 1;
+        if target_degree == 1 || key.degree(target_degree) != Some(target) {
+            return None;
+        }
+        let target_quality = match (key.mode(), target_degree) {
+            (KeyMode::Minor, 5) => Major,
+            _ => key.triad(target_degree)?.quality(),
+        };
+        if target_quality == Diminished {
+            return None;
+        }
+        Some(
+            RomanNumeral::new(degree, chord.quality())
+                .with_chord_tones(chord)
+                .applied_to(RomanNumeral::new(target_degree, target_quality)),
+        )
+    }
+
+    // takes the seventh, highest natural extension and inversion of the chord
+    fn with_chord_tones(self, chord: &Chord) -> Self {
+        use NotePitchInterval::*;
+
+        let mut numeral = self;
+        let seventh = match chord.seventh_interval() {
+            Some(MajorSeventh) => Some(ChordSeventh::Major),
+            Some(MinorSeventh) => Some(ChordSeventh::Minor),
+            Some(DiminishedSeventh) => Some(ChordSeventh::Diminished),
+            _ => None,
+        };
+        if let Some(seventh) = seventh {
+            numeral = numeral.with_seventh(seventh);
+            let has = |interval| chord.intervals().contains(&interval);
+            let extension = if has(MajorSixth) {
+                ChordExtensionKind::Thirteenth
+            } else if has(PerfectFourth) && chord.degree_of(PerfectFourth) == 11 {
+                ChordExtensionKind::Eleventh
+            } else if has(MajorSecond) && chord.degree_of(MajorSecond) == 9 {
+                ChordExtensionKind::Ninth
+            } else {
+                ChordExtensionKind::Seventh
+            };
+            numeral = numeral.with_extension(extension);
+        }
+        numeral.with_inversion(chord.inversion_number().unwrap_or(0))
+    }
+
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+    pub fn accidental(&self) -> i8 {
+        self.accidental
+    }
+    pub fn quality(&self) -> ChordQuality {
+        self.quality
+    }
+    pub fn seventh(&self) -> Option<ChordSeventh> {
+        self.seventh
+    }
+    pub fn extension(&self) -> ChordExtensionKind {
+        self.extension
+    }
+    pub fn inversion(&self) -> u8 {
+        self.inversion
+    }
+    pub fn target(&self) -> Option<&RomanNumeral> {
+        self.target.as_deref()
+    }
+    pub fn augmented_sixth(&self) -> Option<AugmentedSixthKind> {
+        self.augmented_sixth
+    }
+    // whether this is the major triad on the lowered second degree
+    pub fn is_neapolitan(&self) -> bool {
+        self.augmented_sixth.is_none()
+            && self.degree == 2
+            && self.accidental == -1
+            && self.quality == ChordQuality::Major
+            && self.seventh.is_none()
+    }
+
+    // the chord the numeral stands for in the key.
+    // applied chords are realized in the key of their target, e.g. V/V in C major is the V of G major.
+    pub fn to_chord(&self, key: &Key) -> Result<Chord, String> {
+        if let Some(target) = &self.target {
+            let target_chord = target.to_chord(key)?;
+            let mode = match target.quality {
+                ChordQuality::Minor | ChordQuality::Diminished => KeyMode::Minor,
+                _ => KeyMode::Major,
+            };
+            let target_key = Key::new(target_chord.root(), mode);
+            let mut applied = self.clone();
+            applied.target = None;
+            return applied.to_chord(&target_key);
+        }
+        if let Some(kind) = self.augmented_sixth {
+            return Ok(kind.to_chord(key));
+        }
+
+        let mut accidental = self.accidental;
+        if key.mode() == KeyMode::Minor
+            && self.degree == 7
+            && self.quality == ChordQuality::Diminished
+        {
+            accidental += 1;
+        }
+        let root = key
+            .degree(self.degree)
+            .and_then(|note| note.altered(accidental))
+            .ok_or(format!(
+                "The root of {self} in {key} needs more than two accidentals"
+            ))?;
+        let mut chord = Chord::new(root, self.quality);
+        if let Some(seventh) = self.seventh {
+            chord = chord.with_seventh(seventh);
+        }
+        if self.extension != ChordExtensionKind::Triad {
+            chord = chord.with_extension(self.extension);
+        }
+        chord.inversion(self.inversion)
+    }
+
+    // writes the numeral with the given accidentals, using superscript and subscript figures for unicode glyphs.
+    // e.g. ii65 and bVII are written ii⁶₅ and ♭VII with unicode glyphs.
+    pub fn format(&self, glyphs: AccidentalGlyphs) -> String {
+        use ChordQuality::*;
+
+        if let Some(kind) = self.augmented_sixth {
+            return match &self.target {
+                Some(target) => format!("{kind}+6/{}", target.format(glyphs)),
+                None => format!("{kind}+6"),
+            };
+        }
+
+        let mut result = String::new();
+        let numeral = NUMERALS[self.degree as usize - 1];
+        match self.quality {
+            _ if self.is_neapolitan() => result.push('N'),
+            Minor | Diminished => {
+                result.push_str(glyphs.accidental(self.accidental));
+                result.push_str(&numeral.to_lowercase());
+            }
+            _ => {
+                result.push_str(glyphs.accidental(self.accidental));
+                result.push_str(numeral);
+            }
+        }
+        match (self.quality, self.seventh) {
+            (Diminished, Some(ChordSeventh::Minor)) => result.push('ø'),
+            (Diminished, _) => result.push('°'),
+            (Augmented, _) => result.push('+'),
+            _ => {}
+        }
+        if self.seventh == Some(ChordSeventh::Major) {
+            result.push('M');
+        }
+
+        let figures: [&str; 2] = match (self.seventh.is_some(), self.inversion) {
+            (false, 0) => ["", ""],
+            (false, 1) => ["6", ""],
+            (false, _) => ["6", "4"],
+            (true, 0) => match self.extension {
+                ChordExtensionKind::Ninth => ["9", ""],
+                ChordExtensionKind::Eleventh => ["11", ""],
+                ChordExtensionKind::Thirteenth => ["13", ""],
+                _ => ["7", ""],
+            },
+            (true, 1) => ["6", "5"],
+            (true, 2) => ["4", "3"],
+            (true, _) => ["4", "2"],
+        };
+        match glyphs {
+            AccidentalGlyphs::Ascii => figures.iter().for_each(|figure| result.push_str(figure)),
+            AccidentalGlyphs::Unicode => {
+                result.extend(figures[0].chars().map(superscript));
+                result.extend(figures[1].chars().map(subscript));
+            }
+        }
+
+        match self.quality {
+            SuspendedSecond => result.push_str("sus2"),
+            SuspendedFourth => result.push_str("sus4"),
+            _ => {}
+        }
+        if let Some(target) = &self.target {
+            result.push('/');
+            result.push_str(&target.format(glyphs));
+        }
+        result
+    }
+}
+
+fn superscript(digit: char) -> char {
+    match digit {
+        '1' => '\u{00B9}',
+        '2' => '\u{00B2}',
+        '3' => '\u{00B3}',
+        '4' => '\u{2074}',
+        '5' => '\u{2075}',
+        '6' => '\u{2076}',
+        '7' => '\u{2077}',
+        '9' => '\u{2079}',
+        _ => digit,
+    }
+}
+
+fn subscript(digit: char) -> char {
+    match digit {
+        '2' => '\u{2082}',
+        '3' => '\u{2083}',
+        '4' => '\u{2084}',
+        '5' => '\u{2085}',
+        _ => digit,
+    }
+}
+
+// turns unicode accidentals and figures into ascii so a single regex can parse both
+fn normalize(numeral: &str) -> String {
+    numeral
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '\u{266D}' => 'b',
+            '\u{266F}' => '#',
+            '\u{00B9}' => '1',
+            '\u{00B2}' | '\u{2082}' => '2',
+            '\u{00B3}' | '\u{2083}' => '3',
+            '\u{2074}' | '\u{2084}' => '4',
+            '\u{2075}' | '\u{2085}' => '5',
+            '\u{2076}' => '6',
+            '\u{2077}' => '7',
+            '\u{2079}' => '9',
+            _ => c,
+        })
+        .collect()
+}
+
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(AccidentalGlyphs::Ascii))
+    }
+}
+
+impl TryFrom<&str> for RomanNumeral {
+    type Error = String;
+
+    fn try_from(numeral: &str) -> Result<Self, Self::Error> {
+        let normalized = normalize(numeral);
+        if let Some(captures) = AUGMENTED_SIXTH_REGEX.captures(&normalized) {
+            let kind = match &captures["kind"] {
+                "It" => AugmentedSixthKind::Italian,
+                "Fr" => AugmentedSixthKind::French,
+                _ => AugmentedSixthKind::German,
+            };
+            let result = RomanNumeral::new_augmented_sixth(kind);
+            return match captures.name("target") {
+                Some(target) => Ok(result.applied_to(RomanNumeral::try_from(target.as_str())?)),
+                None => Ok(result),
+            };
+        }
+        let captures = ROMAN_NUMERAL_REGEX
+            .captures(&normalized)
+            .ok_or(format!("{numeral} is not a valid roman numeral"))?;
+        let capture = |name: &str| captures.name(name).map(|capture| capture.as_str());
+
+        let accidental = match capture("accidental") {
+            Some("bb") => -2,
+            Some("b") => -1,
+            Some("#") => 1,
+            Some("##") => 2,
+            _ => 0,
+        };
+        let numeral_str = capture("numeral").unwrap();
+        let quality_symbol = capture("quality");
+        if numeral_str == "N" {
+            let is_triad = capture("accidental").is_none()
+                && quality_symbol.is_none()
+                && capture("major").is_none()
+                && capture("suspension").is_none()
+                && matches!(capture("figures"), None | Some("6" | "64"));
+            if !is_triad {
+                return Err(format!("{numeral} is not a valid Neapolitan chord"));
+            }
+        }
+        let (numeral_str, accidental) = match numeral_str {
+            "N" => ("II", -1),
+            _ => (numeral_str, accidental),
+        };
+        let uppercase = numeral_str.chars().all(|c| c.is_uppercase());
+        let degree = NUMERALS
+            .iter()
+            .position(|&candidate| candidate == numeral_str.to_uppercase())
+            .unwrap() as u8
+            + 1;
+        let quality = match (uppercase, quality_symbol, capture("suspension")) {
+            (_, _, Some("sus2")) => ChordQuality::SuspendedSecond,
+            (_, _, Some(_)) => ChordQuality::SuspendedFourth,
+            (true, Some("+"), _) => ChordQuality::Augmented,
+            (true, None, _) => ChordQuality::Major,
+            (false, Some("o" | "°" | "ø"), _) => ChordQuality::Diminished,
+            (false, None, _) => ChordQuality::Minor,
+            _ => {
+                return Err(format!(
+                    "{numeral} has a quality that doesn't match the case of its numeral"
+                ))
+            }
+        };
+
+        let mut result = RomanNumeral::new(degree, quality).with_accidental(accidental);
+        let figures = capture("figures");
+        let has_seventh = quality_symbol == Some("ø")
+            || capture("major").is_some()
+            || matches!(
+                figures,
+                Some("7" | "65" | "43" | "42" | "2" | "9" | "11" | "13")
+            );
+        if has_seventh {
+            let seventh = if capture("major").is_some() {
+                ChordSeventh::Major
+            } else if quality == ChordQuality::Diminished && quality_symbol != Some("ø") {
+                ChordSeventh::Diminished
+            } else {
+                ChordSeventh::Minor
+            };
+            result = result.with_seventh(seventh);
+        }
+        result = match figures {
+            Some("6" | "65") => result.with_inversion(1),
+            Some("64" | "43") => result.with_inversion(2),
+            Some("42" | "2") => result.with_inversion(3),
+            Some("9") => result.with_extension(ChordExtensionKind::Ninth),
+            Some("11") => result.with_extension(ChordExtensionKind::Eleventh),
+            Some("13") => result.with_extension(ChordExtensionKind::Thirteenth),
+            _ => result,
+        };
+        if let Some(target) = capture("target") {
+            result = result.applied_to(RomanNumeral::try_from(target)?);
+        }
+        Ok(result)
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod roman_numeral_test {
+    use super::*;
+
+    fn key(tonic: &str, mode: KeyMode) -> Key {
+        Key::new(Note::try_from(tonic).unwrap(), mode)
+    }
+
+    // chords written as their notes joined by dashes, root first, with an optional slash bass, e.g. D-F-A-C/F
+    fn chords(names: &str) -> Vec<Chord> {
+        names.split(' ').map(chord).collect()
+    }
+
+    fn chord(name: &str) -> Chord {
+        let (tones, bass) = name.split_once('/').unwrap_or((name, ""));
+        let mut notes: Vec<Note> = tones
+            .split('-')
+            .map(|note| Note::try_from(note).unwrap())
+            .collect();
+        let root = notes[0];
+        if !bass.is_empty() {
+            notes.insert(0, Note::try_from(bass).unwrap());
+        }
+        Chord::identify(&notes)
+            .into_iter()
+            .find(|candidate| candidate.root() == root)
+            .unwrap()
+            .chord()
+            .clone()
+    }
+
+    fn analyzed(key: &Key, chords: &[Chord]) -> Vec<String> {
+        chords
+            .iter()
+            .map(|chord| key.analyze(chord).to_string())
+            .collect()
+    }
+
+    fn realized(key: &Key, progression: &str) -> Vec<String> {
+        key.realize(progression)
+            .unwrap()
+            .iter()
+            .map(|chord| chord.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn analyzes_diatonic_chords_in_major() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            analyzed(&c_major, &chords("C-E-G D-F-A-C G-B-D-F B-D-F-A C-E-G-B")),
+            ["I", "ii7", "V7", "viiø7", "IM7"]
+        );
+        assert_eq!(
+            analyzed(&c_major, &chords("B-D-F-Ab Bb-D-F G-B-D-F-A")),
+            ["vii°7", "bVII", "V9"]
+        );
+    }
+
+    #[test]
+    fn analyzes_inversions_with_figures() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            analyzed(
+                &c_major,
+                &chords("C-E-G/E C-E-G/G D-F-A-C/F D-F-A-C/A D-F-A-C/C")
+            ),
+            ["I6", "I64", "ii65", "ii43", "ii42"]
+        );
+    }
+
+    #[test]
+    fn analyzes_chords_in_minor() {
+        let a_minor = key("A", KeyMode::Minor);
+        assert_eq!(
+            analyzed(
+                &a_minor,
+                &chords("A-C-E B-D-F C-E-G E-G#-B-D G#-B-D G-B-D F-A-C")
+            ),
+            ["i", "ii°", "III", "V7", "vii°", "VII", "VI"]
+        );
+    }
+
+    #[test]
+    fn realizes_progressions_in_any_key() {
+        assert_eq!(
+            realized(&key("C", KeyMode::Major), "ii7 V7 I"),
+            ["Dm7", "G7", "C"]
+        );
+        assert_eq!(
+            realized(&key("Eb", KeyMode::Major), "ii7 V7 I"),
+            ["Fm7", "Bb7", "Eb"]
+        );
+        assert_eq!(
+            realized(&key("A", KeyMode::Minor), "iiø7 V7 i vii°7 bII6"),
+            ["Bm7b5", "E7", "Am", "G#dim7", "Bb/D"]
+        );
+        assert_eq!(
+            realized(&key("C", KeyMode::Major), "ii65 V43 IM7 V9"),
+            ["Dm7/F", "G7/D", "Cmaj7", "G9"]
+        );
+    }
+
+    #[test]
+    fn realizes_applied_chords() {
+        assert_eq!(
+            realized(&key("C", KeyMode::Major), "V7/V V/ii vii°7/V"),
+            ["D7", "A", "F#dim7"]
+        );
+    }
+
+    #[test]
+    fn formats_with_unicode_glyphs() {
+        let c_major = key("C", KeyMode::Major);
+        let format = |name: &str| {
+            c_major
+                .analyze(&chord(name))
+                .format(AccidentalGlyphs::Unicode)
+        };
+        assert_eq!(format("D-F-A-C/F"), "ii\u{2076}\u{2085}");
+        assert_eq!(format("Bb-D-F"), "\u{266D}VII");
+        assert_eq!(format("G-B-D-F"), "V\u{2077}");
+
+        let applied = RomanNumeral::try_from("V\u{2077}/V").unwrap();
+        assert_eq!(applied.to_string(), "V7/V");
+        assert_eq!(applied.format(AccidentalGlyphs::Unicode), "V\u{2077}/V");
+    }
+
+    #[test]
+    fn rejects_invalid_numerals() {
+        assert!(RomanNumeral::try_from("H7").is_err());
+        assert!(RomanNumeral::try_from("Vi").is_err());
+        assert!(RomanNumeral::try_from("ii°+").is_err());
+        assert!(RomanNumeral::try_from("V7/X").is_err());
+        assert!(RomanNumeral::try_from("I+o").is_err());
+        assert!(RomanNumeral::try_from("bN6").is_err());
+        assert!(RomanNumeral::try_from("N7").is_err());
+        assert!(RomanNumeral::try_from("It+7").is_err());
+    }
+
+    #[test]
+    fn analyzes_applied_chords() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            analyzed(
+                &c_major,
+                &chords("D-F#-A D-F#-A-C E-G#-B-D/G# C-E-G-Bb A-C#-E")
+            ),
+            ["V/V", "V7/V", "V65/vi", "V7/IV", "V/ii"]
+        );
+        assert_eq!(
+            analyzed(&c_major, &chords("F#-A-C F#-A-C-Eb C#-E-G-Bb F#-A#-C#")),
+            ["vii°/V", "vii°7/V", "vii°7/ii", "#IV"]
+        );
+
+        let a_minor = key("A", KeyMode::Minor);
+        assert_eq!(
+            analyzed(&a_minor, &chords("B-D#-F#-A A-C#-E-G E-G#-B-D G-B-D-F")),
+            ["V7/V", "V7/iv", "V7", "VII7"]
+        );
+    }
+
+    #[test]
+    fn analyzes_borrowed_and_neapolitan_chords() {
+        let c_major = key("C", KeyMode::Major);
+        assert_eq!(
+            analyzed(
+                &c_major,
+                &chords("F-Ab-C Ab-C-Eb Bb-D-F Eb-G-Bb Db-F-Ab/F Db-F-Ab")
+            ),
+            ["iv", "bVI", "bVII", "bIII", "N6", "N"]
+        );
+        assert_eq!(
+            analyzed(&key("A", KeyMode::Minor), &chords("Bb-D-F/D")),
+            ["N6"]
+        );
+        assert_eq!(realized(&c_major, "N6 V7 I"), ["Db/F", "G7", "C"]);
+        assert_eq!(
+            RomanNumeral::try_from("N6")
+                .unwrap()
+                .format(AccidentalGlyphs::Unicode),
+            "N\u{2076}"
+        );
+    }
+
+    #[test]
+    fn realizes_and_analyzes_augmented_sixths() {
+        let c_major = key("C", KeyMode::Major);
+        let a_minor = key("A", KeyMode::Minor);
+        let spelled = |key: &Key, numeral: &str| {
+            key.realize(numeral).unwrap()[0]
+                .notes()
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        assert_eq!(spelled(&c_major, "It+6"), "Ab C F#");
+        assert_eq!(spelled(&c_major, "Fr+6"), "Ab C D F#");
+        assert_eq!(spelled(&c_major, "Ger+6"), "Ab C Eb F#");
+        assert_eq!(spelled(&a_minor, "Ger6"), "F A C D#");
+
+        for numeral in ["It+6", "Fr+6", "Ger+6"] {
+            let chord = &c_major.realize(numeral).unwrap()[0];
+            assert_eq!(c_major.analyze(chord).to_string(), numeral);
+        }
+        assert_eq!(
+            c_major
+                .analyze(&a_minor.realize("Fr+6").unwrap()[0])
+                .augmented_sixth(),
+            None,
+            "F A B D# is not an augmented sixth chord in C major"
+        );
+    }
+
+    #[test]
+    fn wraps_degrees_past_the_seventh() {
+        assert_eq!(RomanNumeral::new(8, ChordQuality::Major).degree(), 1);
+        assert_eq!(RomanNumeral::new(255, ChordQuality::Major).degree(), 3);
+    }
+}
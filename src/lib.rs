@@ -71,6 +71,27 @@
 //! );
 //! assert_eq!(String::from("C D E G A C"), major_pentatonic_scale.print());
 //! ```
+//!
+//! Scales can also be defined from an interval pattern and a name. `CustomScale::new` reads the
+//! steps between the notes, and `CustomScale::from_degrees` reads degrees of the major scale, e.g. "1 2 b3 5 b6".
+//!
+//! ```
+//! use audiate::{
+//!     scale::{CustomScale, Scale, ScaleDirection, ScaleKind},
+//!     Note,
+//! };
+//!
+//! let hirajoshi = CustomScale::new("Hirajoshi", "2 1 4 1 4").unwrap();
+//! let scale = Scale::new(
+//!     Note::try_from("A").unwrap(),
+//!     ScaleKind::Custom(hirajoshi),
+//!     ScaleDirection::Ascending,
+//! );
+//! assert_eq!(String::from("A B C E F A"), scale.print());
+//!
+//! let same = CustomScale::from_degrees("Hirajoshi", "1 2 b3 5 b6").unwrap();
+//! assert_eq!(&ScaleKind::Custom(same), scale.kind());
+//! ```
 
 // #![deny(missing_docs)]
 #![cfg_attr(test, deny(warnings))]
@@ -78,10 +99,21 @@
 
 #[doc = include_str!("../README.md")]
 mod note;
-pub use note::Note;
+pub use note::{Note, NotePitchInterval, Pitch};
 
 pub mod chord;
 pub use chord::{Chord, ChordQuality};
 
 pub mod scale;
 pub use scale::Scale;
+
+pub mod harmony;
+pub use harmony::Key;
+
+pub mod transpose;
+pub use transpose::Transpose;
+
+pub mod collection;
+pub use collection::PitchCollection;
+
+mod fixed;
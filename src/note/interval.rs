@@ -3,9 +3,12 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NotePitchInterval {
     PerfectUnison,
+    // moves to the same letter a semitone up, e.g. C to C#. scales repeating a letter step by it.
+    AugmentedUnison,
     MinorSecond,
     MajorSecond,
     AugmentedSecond,
+    DiminishedThird,
     MinorThird,
     MajorThird,
     DiminishedFourth,
@@ -16,9 +19,12 @@ pub enum NotePitchInterval {
     AugmentedFifth,
     MinorSixth,
     MajorSixth,
+    AugmentedSixth,
     DiminishedSeventh,
     MinorSeventh,
     MajorSeventh,
+    // the inversion of the augmented unison, e.g. C up to Cb
+    DiminishedOctave,
     // Octave,
     // MinorNinth,
     // MajorNinth,
@@ -38,9 +44,11 @@ impl NotePitchInterval {
         use NotePitchInterval::*;
         match self {
             PerfectUnison => PerfectUnison,
+            AugmentedUnison => DiminishedOctave,
             MinorSecond => MajorSeventh,
             MajorSecond => MinorSeventh,
             AugmentedSecond => DiminishedSeventh,
+            DiminishedThird => AugmentedSixth,
             MinorThird => MajorSixth,
             MajorThird => MinorSixth,
             DiminishedFourth => AugmentedFifth,
@@ -51,21 +59,21 @@ impl NotePitchInterval {
             AugmentedFifth => DiminishedFourth,
             MinorSixth => MajorThird,
             MajorSixth => MinorThird,
+            AugmentedSixth => DiminishedThird,
             DiminishedSeventh => AugmentedSecond,
             MinorSeventh => MajorSecond,
             MajorSeventh => MinorSecond,
+            DiminishedOctave => AugmentedUnison,
         }
     }
-}
 
-impl std::ops::Add<NotePitchInterval> for u8 {
-    type Output = Self;
-    fn add(self, other: NotePitchInterval) -> Self {
+    // the number of semitones the interval spans, in the range of 0-11 inclusive
+    pub fn semitones(&self) -> u8 {
         use NotePitchInterval::*;
-        let pitch_interval_value: u8 = match other {
+        match self {
             PerfectUnison => 0,
-            MinorSecond => 1,
-            MajorSecond => 2,
+            AugmentedUnison | MinorSecond => 1,
+            MajorSecond | DiminishedThird => 2,
             AugmentedSecond | MinorThird => 3,
             MajorThird | DiminishedFourth => 4,
             PerfectFourth => 5,
@@ -73,10 +81,63 @@ impl std::ops::Add<NotePitchInterval> for u8 {
             PerfectFifth => 7,
             AugmentedFifth | MinorSixth => 8,
             MajorSixth | DiminishedSeventh => 9,
-            MinorSeventh => 10,
-            MajorSeventh => 11,
+            AugmentedSixth | MinorSeventh => 10,
+            MajorSeventh | DiminishedOctave => 11,
+        }
+    }
+
+    // finds the interval with the given interval number and size in semitones, if one exists.
+    // e.g. a third spanning 3 semitones is a minor third, but no third spans 6 semitones.
+    pub fn from_number_and_semitones(number: u8, semitones: u8) -> Option<NotePitchInterval> {
+        use NotePitchInterval::*;
+        let interval = match (number, semitones % 12) {
+            (1, 0) => PerfectUnison,
+            (1, 1) => AugmentedUnison,
+            (2, 1) => MinorSecond,
+            (2, 2) => MajorSecond,
+            (2, 3) => AugmentedSecond,
+            (3, 2) => DiminishedThird,
+            (3, 3) => MinorThird,
+            (3, 4) => MajorThird,
+            (4, 4) => DiminishedFourth,
+            (4, 5) => PerfectFourth,
+            (4, 6) => AugmentedFourth,
+            (5, 6) => DiminishedFifth,
+            (5, 7) => PerfectFifth,
+            (5, 8) => AugmentedFifth,
+            (6, 8) => MinorSixth,
+            (6, 9) => MajorSixth,
+            (6, 10) => AugmentedSixth,
+            (7, 9) => DiminishedSeventh,
+            (7, 10) => MinorSeventh,
+            (7, 11) => MajorSeventh,
+            (8, 11) => DiminishedOctave,
+            _ => return None,
         };
-        let sum = self + pitch_interval_value;
+        Some(interval)
+    }
+
+    // the interval number, i.e. how many letter names the interval spans counting both ends.
+    // a unison is 1, a second is 2, and so on up to the diminished octave, which is 8.
+    pub fn number(&self) -> u8 {
+        use NotePitchInterval::*;
+        match self {
+            PerfectUnison | AugmentedUnison => 1,
+            MinorSecond | MajorSecond | AugmentedSecond => 2,
+            DiminishedThird | MinorThird | MajorThird => 3,
+            DiminishedFourth | PerfectFourth | AugmentedFourth => 4,
+            DiminishedFifth | PerfectFifth | AugmentedFifth => 5,
+            MinorSixth | MajorSixth | AugmentedSixth => 6,
+            DiminishedSeventh | MinorSeventh | MajorSeventh => 7,
+            DiminishedOctave => 8,
+        }
+    }
+}
+
+impl std::ops::Add<NotePitchInterval> for u8 {
+    type Output = Self;
+    fn add(self, other: NotePitchInterval) -> Self {
+        let sum = self + other.semitones();
         // we want only pitch values to be in the range of 0-11 inclusive
         sum % 12
     }
@@ -4,6 +4,9 @@ pub use note::Note;
 mod interval;
 pub use interval::NotePitchInterval;
 
+mod pitch;
+pub use pitch::Pitch;
+
 mod name;
 mod pitch_variant;
 mod util;
@@ -5,7 +5,7 @@ use lazy_static::lazy_static;
 use regex::Regex;
 
 lazy_static! {
-    static ref NOTE_PITCH_NAMES: [NotePitchName; 7] = [
+    pub(super) static ref NOTE_PITCH_NAMES: [NotePitchName; 7] = [
         NotePitchName::A,
         NotePitchName::B,
         NotePitchName::C,
@@ -29,24 +29,27 @@ pub enum NotePitchName {
 
 impl NotePitchName {
     pub fn by_interval(&self, pitch_interval: NotePitchInterval) -> NotePitchName {
-        use NotePitchInterval::*;
-
         let original_idx = NOTE_PITCH_NAMES
             .iter()
             .position(|&name: &NotePitchName| name == *self)
             .unwrap();
-        let interval_index: usize = match pitch_interval {
-            PerfectUnison => 0,
-            MinorSecond | MajorSecond | AugmentedSecond => 1,
-            MinorThird | MajorThird => 2,
-            DiminishedFourth | PerfectFourth | AugmentedFourth => 3,
-            DiminishedFifth | PerfectFifth | AugmentedFifth => 4,
-            MinorSixth | MajorSixth => 5,
-            DiminishedSeventh | MinorSeventh | MajorSeventh => 6,
-        };
+        let interval_index = (pitch_interval.number() - 1) as usize;
         let new_index = (original_idx + interval_index) % NOTE_PITCH_NAMES.len();
         NOTE_PITCH_NAMES[new_index]
     }
+
+    // the number of letter names between this name and the next occurrence of the other name, going up.
+    // e.g. C to E is 2 steps, and E to C is 5 steps.
+    pub fn steps_to(&self, other: NotePitchName) -> u8 {
+        let position = |name: NotePitchName| {
+            NOTE_PITCH_NAMES
+                .iter()
+                .position(|&candidate: &NotePitchName| candidate == name)
+                .unwrap()
+        };
+        let len = NOTE_PITCH_NAMES.len();
+        ((position(other) + len - position(*self)) % len) as u8
+    }
 }
 
 impl Display for NotePitchName {
@@ -2,12 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use super::{
     interval::NotePitchInterval,
-    name::{is_note_name_valid, NotePitchName},
+    name::{is_note_name_valid, NotePitchName, NOTE_PITCH_NAMES},
     pitch_variant::NotePitchVariant,
     util,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Note {
     name: NotePitchName,
     pitch_variant: NotePitchVariant,
@@ -31,6 +31,54 @@ impl Note {
         self.pitch_variant
     }
 
+    // the pitch class of the note, where C is 0 and B is 11.
+    // enharmonic notes like C# and Db share a pitch class.
+    pub fn pitch_class(&self) -> u8 {
+        (self.pitch_value + 8) % 12
+    }
+
+    pub fn is_enharmonic(&self, other: &Note) -> bool {
+        self.pitch_value == other.pitch_value
+    }
+
+    // the number of semitones from this note up to the next occurrence of the other note, in the range of 0-11 inclusive
+    pub fn semitones_to(&self, other: &Note) -> u8 {
+        (other.pitch_value + 12 - self.pitch_value) % 12
+    }
+
+    // the ascending interval from this note to the other note, respecting how both are spelled.
+    // e.g. C to E is a major third, while C to Fb is a diminished fourth.
+    // returns None when the spelling implies an interval that can't be represented, like C to E#.
+    pub fn interval_to(&self, other: &Note) -> Option<NotePitchInterval> {
+        let number = self.name.steps_to(other.name) + 1;
+        NotePitchInterval::from_number_and_semitones(number, self.semitones_to(other))
+    }
+
+    // the note with the same letter name raised (or lowered, for negative numbers) by the given semitones.
+    // e.g. C altered by -1 is Cb. returns None when that would need more than two accidentals.
+    pub fn altered(&self, semitones: i8) -> Option<Note> {
+        use NotePitchVariant::*;
+        let pitch_variant = match i8::from(self.pitch_variant) + semitones {
+            -2 => Flatdbl,
+            -1 => Flat,
+            0 => Natural,
+            1 => Sharp,
+            2 => Sharpdbl,
+            _ => return None,
+        };
+        Some(Note::new(self.name, pitch_variant))
+    }
+
+    // every spelling of the note's pitch class with at most two accidentals, e.g. E#, F and Gbb for F
+    pub(crate) fn enharmonics(&self) -> impl Iterator<Item = Note> {
+        let pitch_class = self.pitch_class() as i8;
+        NOTE_PITCH_NAMES.iter().filter_map(move |&name| {
+            let natural = Note::new(name, NotePitchVariant::Natural);
+            let offset = (pitch_class - natural.pitch_class() as i8 + 6).rem_euclid(12) - 6;
+            natural.altered(offset)
+        })
+    }
+
     pub fn by_interval_ascending(&self, interval: NotePitchInterval) -> Note {
         self.by_interval(interval)
     }
@@ -45,11 +93,23 @@ impl Note {
         (note_name_pitch + note_variant_pitch) % 12
     }
 
-    fn by_interval(&self, interval: NotePitchInterval) -> Note {
+    // like by_interval_ascending, but returns None instead of panicking when the resulting note
+    // would need more than two accidentals, e.g. a diminished seventh above Fb.
+    pub(crate) fn checked_by_interval_ascending(
+        &self,
+        interval: NotePitchInterval,
+    ) -> Option<Note> {
         let name = self.name.by_interval(interval);
         let root_pitch_value = self.pitch_value + interval;
-        let pitch_variant = calc_pitch_variant_by_name_and_pitch_value(name, root_pitch_value);
-        Note::new(name, pitch_variant)
+        calc_pitch_variant_by_name_and_pitch_value(name, root_pitch_value)
+            .map(|pitch_variant| Note::new(name, pitch_variant))
+    }
+
+    fn by_interval(&self, interval: NotePitchInterval) -> Note {
+        self.checked_by_interval_ascending(interval)
+            .unwrap_or_else(|| {
+                panic!("{self} by {interval:?} can't be spelled with at most two accidentals")
+            })
     }
 }
 
@@ -112,23 +172,13 @@ impl TryFrom<&String> for Note {
 fn calc_pitch_variant_by_name_and_pitch_value(
     name: NotePitchName,
     pitch_value: u8,
-) -> NotePitchVariant {
+) -> Option<NotePitchVariant> {
     use NotePitchVariant::*;
     let note_name_pitch_value = u8::from(name);
 
-    if note_name_pitch_value + Flatdbl == pitch_value {
-        Flatdbl
-    } else if note_name_pitch_value + Flat == pitch_value {
-        Flat
-    } else if note_name_pitch_value + Natural == pitch_value {
-        Natural
-    } else if note_name_pitch_value + Sharp == pitch_value {
-        Sharp
-    } else if note_name_pitch_value + Sharpdbl == pitch_value {
-        Sharpdbl
-    } else {
-        unreachable!("We know the pitch value associated with the given note name is within 2 where this is called, because the starting value is derived from the Note struct, and the new one is calculated with the NotePitchInterval struct.")
-    }
+    [Flatdbl, Flat, Natural, Sharp, Sharpdbl]
+        .into_iter()
+        .find(|&pitch_variant| note_name_pitch_value + pitch_variant == pitch_value)
 }
 
 ////////////////
@@ -942,3 +992,89 @@ mod by_interval_descending_test {
         test_case("G#", MajorSeventh, "A");
     }
 }
+
+#[cfg(test)]
+mod interval_to_test {
+    use super::*;
+    use NotePitchInterval::*;
+
+    fn test_case(start_note_name: &str, end_note_name: &str, expected: Option<NotePitchInterval>) {
+        let start = Note::try_from(start_note_name).unwrap();
+        let end = Note::try_from(end_note_name).unwrap();
+        assert_eq!(start.interval_to(&end), expected);
+    }
+
+    #[test]
+    fn interval_to_respects_spelling() {
+        test_case("C", "C", Some(PerfectUnison));
+        test_case("C", "E", Some(MajorThird));
+        test_case("C", "Fb", Some(DiminishedFourth));
+        test_case("E", "C", Some(MinorSixth));
+        test_case("Ab", "F#", Some(AugmentedSixth));
+        test_case("B", "Ab", Some(DiminishedSeventh));
+        test_case("C", "E#", None);
+    }
+
+    #[test]
+    fn interval_to_is_the_inverse_of_by_interval_ascending() {
+        let root = Note::try_from("Eb").unwrap();
+        for interval in [
+            MinorSecond,
+            AugmentedSecond,
+            MajorThird,
+            AugmentedFourth,
+            MajorSeventh,
+        ] {
+            assert_eq!(
+                root.interval_to(&root.by_interval_ascending(interval)),
+                Some(interval)
+            );
+        }
+    }
+
+    #[test]
+    fn pitch_class_is_shared_by_enharmonic_notes() {
+        assert_eq!(Note::try_from("C").unwrap().pitch_class(), 0);
+        assert_eq!(Note::try_from("B#").unwrap().pitch_class(), 0);
+        assert_eq!(Note::try_from("Db").unwrap().pitch_class(), 1);
+        assert_eq!(Note::try_from("B").unwrap().pitch_class(), 11);
+        assert!(Note::try_from("F#")
+            .unwrap()
+            .is_enharmonic(&Note::try_from("Gb").unwrap()));
+    }
+
+    #[test]
+    fn lists_enharmonic_spellings() {
+        let spellings = |name: &str| {
+            Note::try_from(name)
+                .unwrap()
+                .enharmonics()
+                .map(|note| note.to_string())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(spellings("F"), ["E#", "F", "Gbb"]);
+        assert_eq!(spellings("G#"), ["Ab", "G#"]);
+        assert_eq!(spellings("Cb"), ["A##", "B", "Cb"]);
+    }
+}
+
+#[cfg(test)]
+mod altered_test {
+    use super::*;
+
+    fn test_case(note_name: &str, semitones: i8, expected: Option<&str>) {
+        let note = Note::try_from(note_name).unwrap();
+        let expected = expected.map(|name| Note::try_from(name).unwrap());
+        assert_eq!(note.altered(semitones), expected);
+    }
+
+    #[test]
+    fn altered_keeps_the_letter_name() {
+        test_case("C", -1, Some("Cb"));
+        test_case("C", 2, Some("C##"));
+        test_case("F#", -1, Some("F"));
+        test_case("Bb", -1, Some("Bbb"));
+        test_case("Bb", -2, None);
+        test_case("G##", 1, None);
+    }
+}
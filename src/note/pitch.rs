@@ -0,0 +1,277 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use super::{
+    interval::NotePitchInterval, name::NotePitchName, note::Note, pitch_variant::NotePitchVariant,
+};
+
+// a note in a specific octave, written in scientific pitch notation where C4 is middle C.
+// the octave number belongs to the letter name, so B#3 sounds the same as C4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pitch {
+    note: Note,
+    octave: i8,
+}
+
+impl Pitch {
+    pub fn new(note: Note, octave: i8) -> Self {
+        Self { note, octave }
+    }
+
+    pub fn note(&self) -> Note {
+        self.note
+    }
+
+    pub fn octave(&self) -> i8 {
+        self.octave
+    }
+
+    // the MIDI note number of the pitch, where C4 is 60 and A4 is 69
+    pub fn midi_number(&self) -> i16 {
+        let natural = Note::new(self.note.name(), NotePitchVariant::Natural).pitch_class();
+        let variant = i8::from(self.note.pitch_variant());
+        (self.octave as i16 + 1) * 12 + natural as i16 + variant as i16
+    }
+
+    // the pitch the interval above this one.
+    // panics when the note can't be spelled with at most two accidentals, or would be above octave 127.
+    pub fn by_interval_ascending(&self, interval: NotePitchInterval) -> Pitch {
+        self.checked_by_interval_ascending(interval)
+            .unwrap_or_else(|| {
+                panic!("{self} up by {interval:?} can't be spelled or goes past octave 127")
+            })
+    }
+
+    // like by_interval_ascending, but returns None instead of panicking
+    pub fn checked_by_interval_ascending(&self, interval: NotePitchInterval) -> Option<Pitch> {
+        let note = self.note.checked_by_interval_ascending(interval)?;
+        // the octave number changes whenever the letter name passes C
+        let octave = if letter_index(note.name()) < letter_index(self.note.name())
+            || interval.number() == 8
+        {
+            self.octave.checked_add(1)?
+        } else {
+            self.octave
+        };
+        Some(Pitch::new(note, octave))
+    }
+
+    // the pitch the interval below this one.
+    // panics when the note can't be spelled with at most two accidentals, or would be below octave -128.
+    pub fn by_interval_descending(&self, interval: NotePitchInterval) -> Pitch {
+        self.checked_by_interval_descending(interval)
+            .unwrap_or_else(|| {
+                panic!("{self} down by {interval:?} can't be spelled or goes past octave -128")
+            })
+    }
+
+    // like by_interval_descending, but returns None instead of panicking
+    pub fn checked_by_interval_descending(&self, interval: NotePitchInterval) -> Option<Pitch> {
+        let note = self.note.checked_by_interval_ascending(interval.invert())?;
+        let octave = if letter_index(note.name()) > letter_index(self.note.name())
+            || interval.number() == 8
+        {
+            self.octave.checked_sub(1)?
+        } else {
+            self.octave
+        };
+        Some(Pitch::new(note, octave))
+    }
+
+    // the same note a number of octaves higher, or lower for negative numbers.
+    // panics when the octave number doesn't fit in an i8.
+    pub fn by_octaves(&self, octaves: i8) -> Pitch {
+        self.checked_by_octaves(octaves).unwrap_or_else(|| {
+            panic!("{self} moved by {octaves} octaves goes past octave -128 or 127")
+        })
+    }
+
+    // like by_octaves, but returns None instead of panicking
+    pub fn checked_by_octaves(&self, octaves: i8) -> Option<Pitch> {
+        Some(Pitch::new(self.note, self.octave.checked_add(octaves)?))
+    }
+
+    // the lowest pitch of the given note that sounds above this pitch.
+    // panics when that would be above octave 127.
+    pub fn next_above(&self, note: Note) -> Pitch {
+        self.checked_next_above(note)
+            .unwrap_or_else(|| panic!("the {note} above {self} goes past octave 127"))
+    }
+
+    // like next_above, but returns None instead of panicking
+    pub fn checked_next_above(&self, note: Note) -> Option<Pitch> {
+        let mut result = Pitch::new(note, self.octave.saturating_sub(1));
+        while result.midi_number() <= self.midi_number() {
+            result = result.checked_by_octaves(1)?;
+        }
+        Some(result)
+    }
+
+    // the highest pitch of the given note that sounds below this pitch.
+    // panics when that would be below octave -128.
+    pub fn next_below(&self, note: Note) -> Pitch {
+        self.checked_next_below(note)
+            .unwrap_or_else(|| panic!("the {note} below {self} goes past octave -128"))
+    }
+
+    // like next_below, but returns None instead of panicking
+    pub fn checked_next_below(&self, note: Note) -> Option<Pitch> {
+        let mut result = Pitch::new(note, self.octave.saturating_add(1));
+        while result.midi_number() >= self.midi_number() {
+            result = result.checked_by_octaves(-1)?;
+        }
+        Some(result)
+    }
+}
+
+fn letter_index(name: NotePitchName) -> u8 {
+    NotePitchName::C.steps_to(name)
+}
+
+impl Ord for Pitch {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |pitch: &Pitch| {
+            (
+                pitch.midi_number(),
+                pitch.octave,
+                letter_index(pitch.note.name()),
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for Pitch {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Pitch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let note = self.note;
+        let octave = self.octave;
+        write!(f, "{note}{octave}")
+    }
+}
+
+impl TryFrom<&str> for Pitch {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        let name = name.trim();
+        let split = name
+            .find(|c: char| c.is_ascii_digit() || c == '-')
+            .ok_or(format!("{name} is missing an octave number"))?;
+        let note = Note::try_from(&name[..split])?;
+        let octave = name[split..]
+            .parse::<i8>()
+            .map_err(|_| format!("{} is not a valid octave number", &name[split..]))?;
+        Ok(Pitch::new(note, octave))
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod pitch_test {
+    use super::*;
+    use NotePitchInterval::*;
+
+    fn pitch(name: &str) -> Pitch {
+        Pitch::try_from(name).unwrap()
+    }
+
+    #[test]
+    fn parses_and_prints_scientific_pitch_notation() {
+        assert_eq!(pitch("C4").to_string(), "C4");
+        assert_eq!(pitch("f#3").to_string(), "F#3");
+        assert_eq!(pitch("Bb-1").octave(), -1);
+        assert!(Pitch::try_from("C").is_err());
+        assert!(Pitch::try_from("H4").is_err());
+    }
+
+    #[test]
+    fn midi_number_follows_octave_of_letter() {
+        assert_eq!(pitch("C4").midi_number(), 60);
+        assert_eq!(pitch("A4").midi_number(), 69);
+        assert_eq!(pitch("B#3").midi_number(), 60);
+        assert_eq!(pitch("Cb4").midi_number(), 59);
+        assert_eq!(pitch("C-1").midi_number(), 0);
+    }
+
+    #[test]
+    fn by_interval_crosses_octaves_at_c() {
+        assert_eq!(pitch("A3").by_interval_ascending(MinorThird), pitch("C4"));
+        assert_eq!(
+            pitch("G4").by_interval_ascending(PerfectFourth),
+            pitch("C5")
+        );
+        assert_eq!(pitch("E4").by_interval_ascending(MinorSecond), pitch("F4"));
+        assert_eq!(pitch("C4").by_interval_descending(MinorThird), pitch("A3"));
+        assert_eq!(pitch("D4").by_interval_descending(MajorSecond), pitch("C4"));
+        assert_eq!(
+            pitch("C4").by_interval_ascending(AugmentedUnison),
+            pitch("C#4")
+        );
+        assert_eq!(
+            pitch("C4").by_interval_ascending(DiminishedOctave),
+            pitch("Cb5")
+        );
+        assert_eq!(
+            pitch("C4").by_interval_descending(AugmentedUnison),
+            pitch("Cb4")
+        );
+        assert_eq!(
+            pitch("C4").by_interval_descending(DiminishedOctave),
+            pitch("C#3")
+        );
+    }
+
+    #[test]
+    fn next_above_and_below() {
+        let e = Note::try_from("E").unwrap();
+        assert_eq!(pitch("C4").next_above(e), pitch("E4"));
+        assert_eq!(pitch("E4").next_above(e), pitch("E5"));
+        assert_eq!(pitch("C4").next_below(e), pitch("E3"));
+    }
+
+    #[test]
+    fn orders_by_sound() {
+        assert!(pitch("B3") < pitch("C4"));
+        assert!(pitch("B#3") < pitch("C4"));
+        assert!(pitch("Cb4") < pitch("C4"));
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        assert_eq!(
+            pitch("B127").checked_by_interval_ascending(MajorSecond),
+            None
+        );
+        assert_eq!(
+            pitch("A127").checked_by_interval_ascending(MajorSecond),
+            Some(pitch("B127"))
+        );
+        assert_eq!(
+            pitch("C-128").checked_by_interval_descending(MinorSecond),
+            None
+        );
+        assert_eq!(
+            pitch("C127").checked_by_interval_ascending(DiminishedOctave),
+            None
+        );
+        assert_eq!(
+            pitch("Fb4").checked_by_interval_ascending(DiminishedSeventh),
+            None
+        );
+        assert_eq!(pitch("C127").checked_by_octaves(1), None);
+        assert_eq!(pitch("C-127").checked_by_octaves(-1), Some(pitch("C-128")));
+        let c = Note::try_from("C").unwrap();
+        assert_eq!(pitch("C127").checked_next_above(c), None);
+        assert_eq!(pitch("C-128").checked_next_below(c), None);
+        assert_eq!(pitch("D-128").checked_next_below(c), Some(pitch("C-128")));
+    }
+}
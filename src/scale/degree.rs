@@ -0,0 +1,195 @@
+use std::fmt::{Display, Formatter};
+
+use crate::note::Note;
+
+use super::Scale;
+
+// a position in a scale, counting the root as 1, with the semitones a note is raised or lowered from it.
+// e.g. Eb is b3 of C major and F# is #4.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScaleDegree {
+    number: usize,
+    alteration: i8,
+}
+
+impl ScaleDegree {
+    pub fn number(&self) -> usize {
+        self.number
+    }
+    pub fn alteration(&self) -> i8 {
+        self.alteration
+    }
+    // whether the note is one of the scale's own
+    pub fn is_diatonic(&self) -> bool {
+        self.alteration == 0
+    }
+}
+
+impl Display for ScaleDegree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let accidental = if self.alteration < 0 { "b" } else { "#" };
+        let accidentals = accidental.repeat(self.alteration.unsigned_abs() as usize);
+        write!(f, "{accidentals}{}", self.number)
+    }
+}
+
+impl Scale {
+    // the note on the nth degree, counting the root as 1.
+    // degrees past the last one continue in the next octave, so the 9th degree of a major scale is its 2nd.
+    pub fn degree(&self, n: usize) -> Option<Note> {
+        let index = n.checked_sub(1)?;
+        Some(self.degrees()[index % self.degrees().len()])
+    }
+
+    // where the note lies in the scale.
+    // notes outside the scale are an alteration of the degree with the same letter, e.g. Eb is b3 of C major.
+    // in scales without that letter, a note enharmonic to a degree is that degree, and any other note
+    // raises the degree below it, e.g. F is #3 of C major pentatonic.
+    pub fn degree_of(&self, note: &Note) -> ScaleDegree {
+        let degree = |index: usize, alteration: i8| ScaleDegree {
+            number: index + 1,
+            alteration,
+        };
+        if let Some(index) = self.degrees().iter().position(|tone| tone == note) {
+            return degree(index, 0);
+        }
+        let same_letter = self
+            .degrees()
+            .iter()
+            .enumerate()
+            .filter(|(_, tone)| tone.name() == note.name())
+            .map(|(index, tone)| {
+                let semitones = tone.semitones_to(note) as i8;
+                let alteration = if semitones > 6 {
+                    semitones - 12
+                } else {
+                    semitones
+                };
+                (index, alteration)
+            })
+            .min_by_key(|(_, alteration)| alteration.abs());
+        if let Some((index, alteration)) = same_letter {
+            return degree(index, alteration);
+        }
+        let root = self.root();
+        let semitones = root.semitones_to(note);
+        let (index, below) = self
+            .degrees()
+            .iter()
+            .enumerate()
+            .map(|(index, tone)| (index, root.semitones_to(tone)))
+            .rfind(|&(_, tone)| tone <= semitones)
+            .unwrap_or((0, 0));
+        degree(index, (semitones - below) as i8)
+    }
+
+    // whether the note is in the scale with the same spelling, so C# is in D major but Db isn't
+    pub fn contains(&self, note: &Note) -> bool {
+        self.degrees().contains(note)
+    }
+
+    // whether the note or one enharmonic to it is in the scale, so both C# and Db are in D major
+    pub fn contains_enharmonic(&self, note: &Note) -> bool {
+        self.degrees().iter().any(|tone| tone.is_enharmonic(note))
+    }
+
+    // the note the given number of scale steps away, going down for negative steps.
+    // e.g. two steps up from E in C major is G, and one step down from C is B.
+    // None when the note isn't in the scale, even enharmonically.
+    pub fn step(&self, from: &Note, by: isize) -> Option<Note> {
+        let index = self
+            .degrees()
+            .iter()
+            .position(|tone| tone == from)
+            .or_else(|| {
+                self.degrees()
+                    .iter()
+                    .position(|tone| tone.is_enharmonic(from))
+            })?;
+        let length = self.degrees().len() as isize;
+        Some(self.degrees()[(index as isize + by).rem_euclid(length) as usize])
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod degree_test {
+    use super::*;
+    use crate::scale::{ScaleDirection, ScaleKind};
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn scale(root: &str, kind: ScaleKind) -> Scale {
+        Scale::new(note(root), kind, ScaleDirection::Ascending)
+    }
+
+    fn degree_of(scale: &Scale, name: &str) -> String {
+        scale.degree_of(&note(name)).to_string()
+    }
+
+    #[test]
+    fn finds_notes_by_degree() {
+        let d_major = scale("D", ScaleKind::Major);
+        assert_eq!(d_major.degree(1), Some(note("D")));
+        assert_eq!(d_major.degree(3), Some(note("F#")));
+        assert_eq!(d_major.degree(9), Some(note("E")));
+        assert_eq!(d_major.degree(0), None);
+
+        let descending = Scale::new(note("D"), ScaleKind::Major, ScaleDirection::Descending);
+        assert_eq!(descending.degree(2), Some(note("E")));
+        assert_eq!(descending.tones()[1], note("C#"));
+        assert_eq!(descending.root(), note("D"));
+    }
+
+    #[test]
+    fn finds_the_degree_of_notes() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(degree_of(&c_major, "E"), "3");
+        assert_eq!(degree_of(&c_major, "Eb"), "b3");
+        assert_eq!(degree_of(&c_major, "F#"), "#4");
+        assert_eq!(degree_of(&c_major, "Bbb"), "bb7");
+        assert_eq!(degree_of(&c_major, "Cb"), "b1");
+        assert!(c_major.degree_of(&note("G")).is_diatonic());
+        assert!(!c_major.degree_of(&note("Ab")).is_diatonic());
+    }
+
+    #[test]
+    fn finds_the_degree_of_notes_in_scales_without_every_letter() {
+        let pentatonic = scale("C", ScaleKind::MajorPentatonic);
+        assert_eq!(degree_of(&pentatonic, "A"), "5");
+        assert_eq!(degree_of(&pentatonic, "Ab"), "b5");
+        assert_eq!(degree_of(&pentatonic, "F"), "#3");
+        assert_eq!(degree_of(&pentatonic, "E#"), "#3");
+        assert_eq!(degree_of(&pentatonic, "Bb"), "#5");
+        assert_eq!(degree_of(&pentatonic, "Fb"), "3");
+
+        let blues = scale("C", ScaleKind::MinorBlues);
+        assert_eq!(degree_of(&blues, "Gb"), "4");
+        assert_eq!(degree_of(&blues, "G"), "5");
+        assert_eq!(degree_of(&blues, "F#"), "#3");
+    }
+
+    #[test]
+    fn checks_membership() {
+        let d_major = scale("D", ScaleKind::Major);
+        assert!(d_major.contains(&note("C#")));
+        assert!(!d_major.contains(&note("Db")));
+        assert!(d_major.contains_enharmonic(&note("Db")));
+        assert!(!d_major.contains_enharmonic(&note("C")));
+    }
+
+    #[test]
+    fn steps_through_the_scale() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(c_major.step(&note("E"), 2), Some(note("G")));
+        assert_eq!(c_major.step(&note("C"), -1), Some(note("B")));
+        assert_eq!(c_major.step(&note("B"), 9), Some(note("D")));
+        assert_eq!(c_major.step(&note("Fb"), 1), Some(note("F")));
+        assert_eq!(c_major.step(&note("F#"), 1), None);
+    }
+}
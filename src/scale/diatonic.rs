@@ -0,0 +1,193 @@
+use crate::note::{Note, Pitch};
+
+use super::Scale;
+
+impl Scale {
+    // the note moved by a number of scale steps, going down for negative steps.
+    // a third up in C major is two steps, so C becomes E but D becomes F.
+    // notes outside the scale keep their alteration, so F# a third up in C major is A#.
+    // None when the result would need more than two accidentals.
+    pub fn transpose_note(&self, note: &Note, steps: isize) -> Option<Note> {
+        let degree = self.degree_of(note);
+        // only the steps within an octave matter, so huge numbers of steps don't overflow
+        let steps = steps.rem_euclid(self.degrees().len() as isize);
+        let target = self.degree_at(degree.number() as isize - 1 + steps);
+        target.altered(degree.alteration())
+    }
+
+    // every note of a melody moved by the same number of scale steps
+    pub fn transpose_notes(&self, notes: &[Note], steps: isize) -> Option<Vec<Note>> {
+        notes
+            .iter()
+            .map(|note| self.transpose_note(note, steps))
+            .collect()
+    }
+
+    // like transpose_note, but keeps track of the octave, so B4 a third up in C major is D5.
+    // None as well when the result would be outside octaves -128 to 127.
+    pub fn transpose_pitch(&self, pitch: &Pitch, steps: isize) -> Option<Pitch> {
+        let degree = self.degree_of(&pitch.note());
+        let index = degree.number() as isize - 1;
+        let unaltered = self.degree_pitch(index, 0)?;
+        let sounding = unaltered.midi_number() + degree.alteration() as i16;
+        let octaves = (pitch.midi_number() - sounding).div_euclid(12) as isize;
+
+        let target = self.degree_pitch(index.checked_add(steps)?, octaves)?;
+        let note = target.note().altered(degree.alteration())?;
+        Some(Pitch::new(note, target.octave()))
+    }
+
+    // every pitch of a melody moved by the same number of scale steps
+    pub fn transpose_pitches(&self, pitches: &[Pitch], steps: isize) -> Option<Vec<Pitch>> {
+        pitches
+            .iter()
+            .map(|pitch| self.transpose_pitch(pitch, steps))
+            .collect()
+    }
+
+    // the degree with the given 0-based index, wrapping around the octave
+    fn degree_at(&self, index: isize) -> Note {
+        let length = self.degrees().len() as isize;
+        self.degrees()[index.rem_euclid(length) as usize]
+    }
+
+    // the pitch of the degree with the given 0-based index, counting from the root in the given octave.
+    // None when its octave number doesn't fit in an i8.
+    fn degree_pitch(&self, index: isize, octave: isize) -> Option<Pitch> {
+        let length = self.degrees().len() as isize;
+        let mut pitch = Pitch::new(self.root(), 0);
+        let intervals = self.kind().steps();
+        for &interval in intervals.iter().take(index.rem_euclid(length) as usize) {
+            pitch = pitch.by_interval_ascending(interval);
+        }
+        let octaves = index.div_euclid(length).checked_add(octave)?;
+        pitch.checked_by_octaves(i8::try_from(octaves).ok()?)
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod diatonic_test {
+    use super::*;
+    use crate::scale::{ScaleDirection, ScaleKind};
+
+    fn scale(root: &str, kind: ScaleKind) -> Scale {
+        Scale::new(
+            Note::try_from(root).unwrap(),
+            kind,
+            ScaleDirection::Ascending,
+        )
+    }
+
+    fn notes(names: &str) -> Vec<Note> {
+        names
+            .split(' ')
+            .map(|name| Note::try_from(name).unwrap())
+            .collect()
+    }
+
+    fn pitches(names: &str) -> Vec<Pitch> {
+        names
+            .split(' ')
+            .map(|name| Pitch::try_from(name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn transposes_notes_by_scale_steps() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(
+            c_major.transpose_notes(&notes("C D E F G"), 2),
+            Some(notes("E F G A B"))
+        );
+        assert_eq!(
+            c_major.transpose_notes(&notes("C D E"), -1),
+            Some(notes("B C D"))
+        );
+        assert_eq!(
+            c_major.transpose_notes(&notes("C D"), 7),
+            Some(notes("C D"))
+        );
+
+        let d_minor = scale("D", ScaleKind::HarmonicMinor);
+        assert_eq!(
+            d_minor.transpose_notes(&notes("D E F"), 4),
+            Some(notes("A Bb C#"))
+        );
+    }
+
+    #[test]
+    fn keeps_the_alteration_of_chromatic_notes() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(
+            c_major.transpose_note(&Note::try_from("F#").unwrap(), 2),
+            Some(Note::try_from("A#").unwrap())
+        );
+        assert_eq!(
+            c_major.transpose_note(&Note::try_from("Eb").unwrap(), 1),
+            Some(Note::try_from("Fb").unwrap())
+        );
+
+        let g_sharp_major = scale("G#", ScaleKind::Major);
+        assert_eq!(
+            g_sharp_major.transpose_note(&Note::try_from("C##").unwrap(), 3),
+            None
+        );
+    }
+
+    #[test]
+    fn transposes_pitches_across_octaves() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(
+            c_major.transpose_pitches(&pitches("A4 B4 C5"), 2),
+            Some(pitches("C5 D5 E5"))
+        );
+        assert_eq!(
+            c_major.transpose_pitches(&pitches("C4 D4"), -2),
+            Some(pitches("A3 B3"))
+        );
+        assert_eq!(
+            c_major.transpose_pitches(&pitches("E4"), 9),
+            Some(pitches("G5"))
+        );
+        assert_eq!(
+            c_major.transpose_pitch(&Pitch::try_from("F#4").unwrap(), -4),
+            Some(Pitch::try_from("B#3").unwrap())
+        );
+
+        let a_pentatonic = scale("A", ScaleKind::MinorPentatonic);
+        assert_eq!(
+            a_pentatonic.transpose_pitches(&pitches("G4 A4 C5"), 1),
+            Some(pitches("A4 C5 D5"))
+        );
+        assert_eq!(
+            a_pentatonic.transpose_pitches(&pitches("E4 G4"), -1),
+            Some(pitches("D4 E4"))
+        );
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let c_major = scale("C", ScaleKind::Major);
+        let c4 = Pitch::try_from("C4").unwrap();
+        assert_eq!(c_major.transpose_pitch(&c4, 2000), None);
+        assert_eq!(c_major.transpose_pitch(&c4, isize::MAX), None);
+        assert_eq!(c_major.transpose_pitch(&c4, isize::MIN), None);
+        assert_eq!(
+            c_major.transpose_pitch(&c4, 7 * 123),
+            Some(Pitch::try_from("C127").unwrap())
+        );
+        assert_eq!(
+            c_major.transpose_pitch(&Pitch::try_from("C127").unwrap(), -7 * 255),
+            Some(Pitch::try_from("C-128").unwrap())
+        );
+        // isize::MAX steps are a whole number of octaves
+        assert_eq!(
+            c_major.transpose_note(&Note::try_from("D").unwrap(), isize::MAX),
+            Some(Note::try_from("D").unwrap())
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::note::{Note, Pitch};
+
+use super::{Scale, ScaleDirection};
+
+// the shape repeated on every degree of a scale exercise
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScalePattern {
+    // the scale itself, one degree after the other
+    Straight,
+    // pairs of notes a diatonic interval apart, e.g. Intervals(3) plays thirds: C E, D F, E G...
+    Intervals(usize),
+    // a group of degrees counted from each starting degree, e.g. Cell(vec![1, 2, 3, 5]) plays C D E G, D E F A...
+    Cell(Vec<usize>),
+    // the triad broken on each degree: C E G, D F A...
+    Arpeggios,
+}
+
+impl ScalePattern {
+    // the steps above the starting degree for every note of the shape
+    fn offsets(&self) -> Result<Vec<isize>, String> {
+        let degrees = match self {
+            ScalePattern::Straight => vec![1],
+            ScalePattern::Intervals(number) => vec![1, *number],
+            ScalePattern::Cell(degrees) => degrees.clone(),
+            ScalePattern::Arpeggios => vec![1, 3, 5],
+        };
+        if degrees.is_empty() {
+            return Err("A scale pattern needs at least one degree".to_string());
+        }
+        degrees
+            .iter()
+            .map(|&degree| match degree {
+                0 => Err("Scale pattern degrees start from 1".to_string()),
+                _ => Ok(degree as isize - 1),
+            })
+            .collect()
+    }
+}
+
+impl Scale {
+    // a practice exercise playing the pattern on every degree, from the root in the given octave and across
+    // a number of octaves, ending on the root where the run stops.
+    // the scale's direction decides whether the exercise goes up, down or both, and going down follows the
+    // descending form of the scale, so A melodic minor comes back down as natural minor.
+    // descending runs start from the top root and mirror the pattern, so thirds going down in C major are C A, B G...
+    // fails when the exercise would go past the lowest or highest octave a pitch can be in.
+    pub fn exercise(
+        &self,
+        pattern: &ScalePattern,
+        octave: i8,
+        octaves: usize,
+    ) -> Result<Vec<Pitch>, String> {
+        use ScaleDirection::*;
+        if octaves == 0 {
+            return Err("A scale exercise needs at least one octave".to_string());
+        }
+        let offsets = pattern.offsets()?;
+        let runs: &[bool] = match self.direction() {
+            Ascending => &[true],
+            Descending => &[false],
+            AscendingDescending => &[true, false],
+            DescendingAscending => &[false, true],
+        };
+        let mut result: Vec<Pitch> = vec![];
+        for &ascending in runs {
+            let run = self
+                .exercise_run(&offsets, octave, octaves, ascending)
+                .ok_or(format!(
+                    "A {octaves} octave exercise from octave {octave} goes past octave -128 or 127"
+                ))?;
+            // a run starts where the previous one ended, so the turning note is played once
+            let skip = usize::from(result.last() == run.first());
+            result.extend_from_slice(&run[skip..]);
+        }
+        Ok(result)
+    }
+
+    fn exercise_run(
+        &self,
+        offsets: &[isize],
+        octave: i8,
+        octaves: usize,
+        ascending: bool,
+    ) -> Option<Vec<Pitch>> {
+        let ladder = self.ladder(octave, ascending)?;
+        let top = isize::try_from(ladder.len().checked_mul(octaves)?).ok()?;
+        let pitch_at = |index: isize| {
+            let length = ladder.len() as isize;
+            let octaves = i8::try_from(index.div_euclid(length)).ok()?;
+            ladder[index.rem_euclid(length) as usize].checked_by_octaves(octaves)
+        };
+        let mut result = vec![];
+        if ascending {
+            for start in 0..top {
+                for &offset in offsets {
+                    result.push(pitch_at(start.checked_add(offset)?)?);
+                }
+            }
+            result.push(pitch_at(top)?);
+        } else {
+            for start in (1..=top).rev() {
+                for &offset in offsets {
+                    result.push(pitch_at(start.checked_sub(offset)?)?);
+                }
+            }
+            result.push(pitch_at(0)?);
+        }
+        Some(result)
+    }
+
+    // the degrees of the ascending or descending form of the scale as pitches, rising from the root.
+    // None when they would rise past octave 127.
+    fn ladder(&self, octave: i8, ascending: bool) -> Option<Vec<Pitch>> {
+        let degrees: Vec<Note> = match ascending {
+            true => self.degrees().to_vec(),
+            false => {
+                let descending = Scale::checked_new(
+                    self.root(),
+                    self.kind().clone(),
+                    ScaleDirection::Descending,
+                );
+                match descending {
+                    Some(scale) => scale.tones()[1..].iter().rev().copied().collect(),
+                    None => self.degrees().to_vec(),
+                }
+            }
+        };
+        let mut result = vec![Pitch::new(self.root(), octave)];
+        for &note in &degrees[1..] {
+            let next = result[result.len() - 1].checked_next_above(note)?;
+            result.push(next);
+        }
+        Some(result)
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod exercise_test {
+    use super::*;
+    use crate::scale::ScaleKind;
+    use ScaleDirection::*;
+
+    fn scale(root: &str, kind: ScaleKind, direction: ScaleDirection) -> Scale {
+        Scale::new(Note::try_from(root).unwrap(), kind, direction)
+    }
+
+    fn print(pitches: &[Pitch]) -> String {
+        pitches
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn exercise(scale: &Scale, pattern: ScalePattern, octaves: usize) -> String {
+        print(&scale.exercise(&pattern, 4, octaves).unwrap())
+    }
+
+    #[test]
+    fn plays_the_scale_across_octaves() {
+        let c_major = scale("C", ScaleKind::Major, Ascending);
+        assert_eq!(
+            exercise(&c_major, ScalePattern::Straight, 2),
+            "C4 D4 E4 F4 G4 A4 B4 C5 D5 E5 F5 G5 A5 B5 C6"
+        );
+        let b_major = scale("B", ScaleKind::MajorPentatonic, Descending);
+        assert_eq!(
+            exercise(&b_major, ScalePattern::Straight, 1),
+            "B5 G#5 F#5 D#5 C#5 B4"
+        );
+    }
+
+    #[test]
+    fn follows_the_descending_form_of_the_scale() {
+        let melodic_minor = scale("A", ScaleKind::MelodicMinor, AscendingDescending);
+        assert_eq!(
+            exercise(&melodic_minor, ScalePattern::Straight, 1),
+            "A4 B4 C5 D5 E5 F#5 G#5 A5 G5 F5 E5 D5 C5 B4 A4"
+        );
+        let melodic_minor = scale("A", ScaleKind::MelodicMinor, DescendingAscending);
+        assert_eq!(
+            exercise(&melodic_minor, ScalePattern::Intervals(3), 1),
+            "A5 F5 G5 E5 F5 D5 E5 C5 D5 B4 C5 A4 B4 G4 A4 C5 B4 D5 C5 E5 D5 F#5 E5 G#5 F#5 A5 G#5 B5 A5"
+        );
+    }
+
+    #[test]
+    fn plays_intervals_and_cells_on_every_degree() {
+        let c_major = scale("C", ScaleKind::Major, AscendingDescending);
+        assert_eq!(
+            exercise(&c_major, ScalePattern::Intervals(3), 1),
+            "C4 E4 D4 F4 E4 G4 F4 A4 G4 B4 A4 C5 B4 D5 C5 A4 B4 G4 A4 F4 G4 E4 F4 D4 E4 C4 D4 B3 C4"
+        );
+        let g_major = scale("G", ScaleKind::Major, Ascending);
+        assert_eq!(
+            exercise(&g_major, ScalePattern::Intervals(4), 1),
+            "G4 C5 A4 D5 B4 E5 C5 F#5 D5 G5 E5 A5 F#5 B5 G5"
+        );
+        let d_minor = scale("D", ScaleKind::Minor, Descending);
+        assert_eq!(
+            exercise(&d_minor, ScalePattern::Cell(vec![1, 2, 3, 5]), 1),
+            "D5 C5 Bb4 G4 C5 Bb4 A4 F4 Bb4 A4 G4 E4 A4 G4 F4 D4 G4 F4 E4 C4 F4 E4 D4 Bb3 E4 D4 C4 A3 D4"
+        );
+    }
+
+    #[test]
+    fn breaks_the_triad_on_every_degree() {
+        let pentatonic = scale("C", ScaleKind::MajorPentatonic, Ascending);
+        assert_eq!(
+            exercise(&pentatonic, ScalePattern::Arpeggios, 1),
+            "C4 E4 A4 D4 G4 C5 E4 A4 D5 G4 C5 E5 A4 D5 G5 C5"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_patterns() {
+        let c_major = scale("C", ScaleKind::Major, Ascending);
+        assert!(c_major.exercise(&ScalePattern::Straight, 4, 0).is_err());
+        assert!(c_major.exercise(&ScalePattern::Cell(vec![]), 4, 1).is_err());
+        assert!(c_major
+            .exercise(&ScalePattern::Cell(vec![0, 2]), 4, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let c_major = scale("C", ScaleKind::Major, AscendingDescending);
+        assert!(c_major.exercise(&ScalePattern::Straight, 4, 200).is_err());
+        assert!(c_major.exercise(&ScalePattern::Straight, 127, 1).is_err());
+        assert!(c_major
+            .exercise(&ScalePattern::Straight, 4, usize::MAX)
+            .is_err());
+        assert!(c_major
+            .exercise(&ScalePattern::Cell(vec![1, 2000]), 4, 1)
+            .is_err());
+        assert!(c_major
+            .exercise(&ScalePattern::Intervals(3), -128, 1)
+            .is_err());
+        let top = c_major.exercise(&ScalePattern::Straight, 126, 1).unwrap();
+        assert_eq!(top.iter().max(), Some(&Pitch::try_from("C127").unwrap()));
+    }
+}
@@ -0,0 +1,225 @@
+use std::cmp::Reverse;
+
+use crate::note::Note;
+
+use super::{Scale, ScaleDirection, ScaleKind};
+
+// the roots tried for pitch classes that aren't among the notes, spelled the way key signatures usually are
+const DEFAULT_ROOTS: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B",
+];
+
+// one scale that contains every note of a collection.
+// a lower score means a closer, simpler match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleCandidate {
+    root: Note,
+    kind: ScaleKind,
+    score: u8,
+}
+
+impl ScaleCandidate {
+    pub fn root(&self) -> Note {
+        self.root
+    }
+    pub fn kind(&self) -> &ScaleKind {
+        &self.kind
+    }
+    pub fn scale(&self) -> Scale {
+        Scale::new(self.root, self.kind.clone(), ScaleDirection::Ascending)
+    }
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+}
+
+impl Scale {
+    // returns every built-in scale containing all the notes, best match first.
+    // scales cost more for every one of their notes that isn't played, for every played note they spell
+    // differently, and for being less common, so C D E G A reads as C major pentatonic before C major.
+    // ties go to the scale whose root comes first in the notes, e.g. A C D E G reads as A minor pentatonic.
+    pub fn identify(notes: &[Note]) -> Vec<ScaleCandidate> {
+        let weighted: Vec<(Note, u32)> = notes.iter().map(|&note| (note, 1)).collect();
+        Scale::identify_weighted(&weighted)
+    }
+
+    // like identify, but ties go to the scale whose root carries the most weight,
+    // e.g. the total duration the note is played for.
+    pub fn identify_weighted(notes: &[(Note, u32)]) -> Vec<ScaleCandidate> {
+        let mut unique: Vec<(Note, u32)> = vec![];
+        for &(note, weight) in notes {
+            match unique
+                .iter_mut()
+                .find(|(existing, _)| existing.is_enharmonic(&note))
+            {
+                Some((_, total)) => *total = total.saturating_add(weight),
+                None => unique.push((note, weight)),
+            }
+        }
+        if unique.is_empty() {
+            return vec![];
+        }
+
+        let mut roots: Vec<Note> = unique.iter().map(|&(note, _)| note).collect();
+        for name in DEFAULT_ROOTS {
+            let root = Note::try_from(name).unwrap();
+            if !roots.iter().any(|existing| existing.is_enharmonic(&root)) {
+                roots.push(root);
+            }
+        }
+
+        let played: Vec<Note> = unique.iter().map(|&(note, _)| note).collect();
+        let mut result: Vec<(ScaleCandidate, u32)> = vec![];
+        for root in roots {
+            let root_weight = unique
+                .iter()
+                .find(|(note, _)| note.is_enharmonic(&root))
+                .map_or(0, |&(_, weight)| weight);
+            for kind in ScaleKind::built_in() {
+                if let Some(candidate) = candidate(root, kind, &played) {
+                    result.push((candidate, root_weight));
+                }
+            }
+        }
+        result.sort_by_key(|(candidate, root_weight)| (candidate.score, Reverse(*root_weight)));
+        result.into_iter().map(|(candidate, _)| candidate).collect()
+    }
+}
+
+fn candidate(root: Note, kind: &ScaleKind, notes: &[Note]) -> Option<ScaleCandidate> {
+    // Ionian and Aeolian would only repeat major and minor
+    if matches!(kind, ScaleKind::Ionian | ScaleKind::Aeolian) {
+        return None;
+    }
+    let mut tones = vec![root];
+    for &interval in kind.steps() {
+        let next = tones.last()?.checked_by_interval_ascending(interval)?;
+        tones.push(next);
+    }
+    tones.pop();
+
+    let fits = notes
+        .iter()
+        .all(|note| tones.iter().any(|tone| tone.is_enharmonic(note)));
+    if !fits {
+        return None;
+    }
+    let unplayed = tones
+        .iter()
+        .filter(|tone| !notes.iter().any(|note| note.is_enharmonic(tone)))
+        .count() as u8;
+    let respelled = notes.iter().filter(|note| !tones.contains(note)).count() as u8;
+    let score = 2 * unplayed + 2 * respelled + rarity(kind);
+    Some(ScaleCandidate {
+        root,
+        kind: kind.clone(),
+        score,
+    })
+}
+
+// how unusual the scale is, from 0 for major and minor to 3 for the world scales
+fn rarity(kind: &ScaleKind) -> u8 {
+    use ScaleKind::*;
+    match kind {
+        Major | Minor => 0,
+        Ionian | Dorian | Phrygian | Lydian | Mixolydian | Aeolian | Locrian => 1,
+        MajorPentatonic | MinorPentatonic | HarmonicMinor | MelodicMinor => 1,
+        DorianFlatTwo | LydianAugmented | LydianDominant | MixolydianFlatSix | LocrianSharpTwo
+        | Altered => 2,
+        LocrianSharpSix
+        | IonianSharpFive
+        | DorianSharpFour
+        | PhrygianDominant
+        | LydianSharpTwo
+        | SuperLocrianDoubleFlatSeven => 2,
+        HalfWhole | WholeHalf | WholeTone | Augmented => 2,
+        MinorBlues | MajorBlues | BebopDominant | BebopMajor | BebopMinor => 2,
+        _ => 3,
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod identify_test {
+    use super::*;
+    use ScaleKind::*;
+
+    fn notes(names: &str) -> Vec<Note> {
+        names
+            .split(' ')
+            .map(|name| Note::try_from(name).unwrap())
+            .collect()
+    }
+
+    fn named(candidate: &ScaleCandidate) -> String {
+        format!("{} {}", candidate.root(), candidate.kind())
+    }
+
+    fn best(names: &str) -> String {
+        named(&Scale::identify(&notes(names))[0])
+    }
+
+    #[test]
+    fn identifies_major_and_minor_scales() {
+        assert_eq!(best("C D E F G A B"), "C major");
+        assert_eq!(best("G A B C D E F#"), "G major");
+        assert_eq!(best("Bb C Db Eb F Gb Ab"), "Bb minor");
+        assert_eq!(best("A B C D E F G#"), "A harmonic minor");
+    }
+
+    #[test]
+    fn prefers_scales_without_unplayed_notes() {
+        assert_eq!(best("C D E G A"), "C major pentatonic");
+        assert_eq!(best("A C D E G"), "A minor pentatonic");
+        assert_eq!(best("C Eb F Gb G Bb"), "C minor blues");
+        assert_eq!(best("C D E F# G# A#"), "C whole tone");
+    }
+
+    #[test]
+    fn prefers_scales_that_keep_the_spelling() {
+        let candidates = Scale::identify(&notes("C D E F# G A B"));
+        assert_eq!(named(&candidates[0]), "E minor");
+        assert_eq!(named(&candidates[1]), "G major");
+        assert_eq!(named(&candidates[2]), "C Lydian");
+        assert_eq!(candidates[1].scale().print(), "G A B C D E F# G");
+        assert!(candidates[2].score() > candidates[1].score());
+
+        let candidates = Scale::identify(&notes("C# D# E# F# G# A# B#"));
+        assert_eq!(named(&candidates[0]), "C# major");
+        assert!(candidates
+            .iter()
+            .all(|candidate| candidate.root().to_string() != "Db"));
+    }
+
+    #[test]
+    fn weights_decide_between_relative_scales() {
+        let weighted: Vec<(Note, u32)> = notes("C D E F G A B")
+            .into_iter()
+            .map(|note| (note, if note.to_string() == "A" { 4 } else { 1 }))
+            .collect();
+        let candidates = Scale::identify_weighted(&weighted);
+        assert_eq!(named(&candidates[0]), "A minor");
+        assert_eq!(named(&candidates[1]), "C major");
+
+        let mut weighted = weighted;
+        weighted.push((Note::try_from("A").unwrap(), u32::MAX));
+        let candidates = Scale::identify_weighted(&weighted);
+        assert_eq!(named(&candidates[0]), "A minor");
+    }
+
+    #[test]
+    fn lists_every_scale_containing_the_notes() {
+        let candidates = Scale::identify(&notes("C E G"));
+        assert!(candidates.len() > 10);
+        assert!(candidates
+            .iter()
+            .all(|candidate| candidate.kind() != &Ionian && candidate.kind() != &Aeolian));
+        assert!(candidates
+            .iter()
+            .any(|candidate| named(candidate) == "G major"));
+        assert!(Scale::identify(&[]).is_empty());
+    }
+}
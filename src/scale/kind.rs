@@ -1,9 +1,11 @@
 #![allow(dead_code)]
+use std::fmt::{Display, Formatter};
+
 use crate::note::NotePitchInterval;
 
-use super::ScaleDirection;
+use super::{steps, ScaleDirection};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScaleKind {
     Major,
     Minor,
@@ -18,145 +20,715 @@ pub enum ScaleKind {
     Locrian,
     HarmonicMinor,
     MelodicMinor,
-    // HalfWhole,
-    // WholeHalf,
-    // WholeTone,
+    // the modes of melodic minor, from the second to the seventh
+    DorianFlatTwo,
+    LydianAugmented,
+    // a Mixolydian scale with a raised fourth
+    LydianDominant,
+    MixolydianFlatSix,
+    LocrianSharpTwo,
+    // also called super locrian.
+    // it's spelled with one note per letter, so G altered is G Ab Bb Cb Db Eb F.
+    Altered,
+    // the modes of harmonic minor, from the second to the seventh
+    LocrianSharpSix,
+    IonianSharpFive,
+    DorianSharpFour,
+    PhrygianDominant,
+    LydianSharpTwo,
+    SuperLocrianDoubleFlatSeven,
+    // the symmetric scales don't have one note per letter, so their spelling follows fixed rules.
+    // the diminished scale starting with a half step. its eight notes repeat the third letter,
+    // as the flat and natural third, so C half-whole is C Db Eb E F# G A Bb.
+    HalfWhole,
+    // the diminished scale starting with a whole step. it repeats the sixth letter,
+    // as the flat and natural sixth, so C whole-half is C D Eb F Gb Ab A B.
+    WholeHalf,
+    // six whole steps. the letter below the root is skipped, and the last step back to the root
+    // is a diminished third, so C whole tone is C D E F# G# A#.
+    WholeTone,
+    // minor thirds alternating with half steps. the fourth letter is skipped, which makes the first
+    // and last minor thirds augmented seconds, so C augmented is C D# E G Ab B.
+    Augmented,
+    // blues and bebop scales add chromatic notes to a pentatonic or heptatonic scale, so a letter repeats.
+    // the spelling of each is given by its degrees, e.g. C minor blues is C Eb F Gb G Bb, with the b5 and 5 sharing G.
+    MinorBlues,
+    MajorBlues,
+    BebopDominant,
+    BebopMajor,
+    BebopMinor,
+    // heptatonic scales from around the world, spelled with one letter per note
+    HungarianMinor,
+    HungarianMajor,
+    DoubleHarmonic,
+    NeapolitanMajor,
+    NeapolitanMinor,
+    Enigmatic,
+    Persian,
+    // pentatonic and hexatonic scales, which skip the letters of their missing degrees
+    Hirajoshi,
+    InSen,
+    Iwato,
+    Kumoi,
+    // an approximation of the gamelan tuning in twelve tone equal temperament
+    Pelog,
+    Egyptian,
+    Prometheus,
+    // a scale defined at runtime from an interval pattern
+    Custom(CustomScale),
+}
+
+// a named scale made of the steps between its notes, from the root up to the octave.
+// new reads the steps as letters, W for a whole step, H for a half step and A for an augmented second,
+// e.g. "W W H W W W H", or as semitones, e.g. "2 2 1 2 2 2 1".
+// from_degrees reads degrees of the major scale with accidentals instead, e.g. "1 2 b3 4 5 b6 7".
+// seven note scales given as steps are spelled with one letter per note. steps in other scales are spelled
+// from the root as m2, M2, m3, M3, P4, A4, P5, m6, M6, m7 and M7, so C with "3 2 1 1 3 2" is C Eb F F# G Bb.
+// degrees keep the letter of their number, so a letter repeats when two degrees share a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomScale {
+    name: String,
+    steps: Vec<NotePitchInterval>,
+}
+
+impl CustomScale {
+    pub fn new(name: &str, steps: &str) -> Result<Self, String> {
+        let tokens = tokens(name, steps)?;
+        Ok(CustomScale {
+            name: name.to_string(),
+            steps: steps_from_semitones(steps, &semitone_steps(steps, &tokens)?)?,
+        })
+    }
+
+    pub fn from_degrees(name: &str, degrees: &str) -> Result<Self, String> {
+        let tokens = tokens(name, degrees)?;
+        Ok(CustomScale {
+            name: name.to_string(),
+            steps: steps_from_degrees(degrees, &intervals_from_degrees(degrees, &tokens)?)?,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // the intervals between neighbouring notes, ending with the step back to the octave
+    pub fn steps(&self) -> &[NotePitchInterval] {
+        &self.steps
+    }
+}
+
+const MAJOR_SCALE_SEMITONES: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+fn tokens<'a>(name: &str, pattern: &'a str) -> Result<Vec<&'a str>, String> {
+    let tokens: Vec<&str> = pattern.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Err(format!("The pattern of the {name} scale is empty"));
+    }
+    Ok(tokens)
+}
+
+// the interval from the root to every degree
+fn intervals_from_degrees(
+    pattern: &str,
+    tokens: &[&str],
+) -> Result<Vec<NotePitchInterval>, String> {
+    tokens
+        .iter()
+        .map(|token| {
+            let number = token.trim_start_matches(['b', '#']);
+            let accidentals: i32 = token[..token.len() - number.len()]
+                .chars()
+                .map(|c| if c == 'b' { -1 } else { 1 })
+                .sum();
+            let semitones = number
+                .parse::<u8>()
+                .ok()
+                .filter(|number| (1..=7).contains(number))
+                .map(|number| {
+                    (
+                        number,
+                        MAJOR_SCALE_SEMITONES[number as usize - 1] as i32 + accidentals,
+                    )
+                })
+                .filter(|(_, semitones)| (0..12).contains(semitones));
+            semitones
+                .and_then(|(number, semitones)| {
+                    NotePitchInterval::from_number_and_semitones(number, semitones as u8)
+                })
+                .ok_or(format!(
+                    "{token} in {pattern} is not a degree. Degrees are 1 to 7 with flats or sharps"
+                ))
+        })
+        .collect()
+}
+
+fn semitone_steps(pattern: &str, tokens: &[&str]) -> Result<Vec<u8>, String> {
+    tokens
+        .iter()
+        .map(|token| match token.to_ascii_uppercase().as_str() {
+            "H" => Ok(1),
+            "W" => Ok(2),
+            "A" => Ok(3),
+            number => number
+                .parse()
+                .ok()
+                .filter(|&semitones| semitones > 0)
+                .ok_or(format!(
+                "{token} in {pattern} is not a step. Steps are W, H, A or a number of semitones"
+            )),
+        })
+        .collect()
+}
+
+fn steps_from_semitones(pattern: &str, steps: &[u8]) -> Result<Vec<NotePitchInterval>, String> {
+    use NotePitchInterval::*;
+
+    let total: u32 = steps.iter().map(|&step| step as u32).sum();
+    if total != 12 {
+        return Err(format!(
+            "The steps of {pattern} add up to {total} semitones instead of an octave"
+        ));
+    }
+    if steps.len() == 7 {
+        return steps
+            .iter()
+            .map(|step| match step {
+                1 => Ok(MinorSecond),
+                2 => Ok(MajorSecond),
+                3 => Ok(AugmentedSecond),
+                _ => Err(format!(
+                    "{pattern} has seven notes but a step of {step} semitones, so it can't be spelled with one letter per note"
+                )),
+            })
+            .collect();
+    }
+    let spelling = [
+        MinorSecond,
+        MajorSecond,
+        MinorThird,
+        MajorThird,
+        PerfectFourth,
+        AugmentedFourth,
+        PerfectFifth,
+        MinorSixth,
+        MajorSixth,
+        MinorSeventh,
+        MajorSeventh,
+    ];
+    let mut degrees = vec![PerfectUnison];
+    let mut semitones = 0;
+    for &step in &steps[..steps.len() - 1] {
+        semitones += step as usize;
+        degrees.push(spelling[semitones - 1]);
+    }
+    steps_from_degrees(pattern, &degrees)
+}
+
+// the steps between the degrees, with the last one closing the octave
+fn steps_from_degrees(
+    pattern: &str,
+    degrees: &[NotePitchInterval],
+) -> Result<Vec<NotePitchInterval>, String> {
+    if degrees[0] != NotePitchInterval::PerfectUnison {
+        return Err(format!("{pattern} doesn't start on the root"));
+    }
+    let octave = (8, 12);
+    let positions: Vec<(u8, u8)> = degrees
+        .iter()
+        .map(|degree| (degree.number(), degree.semitones()))
+        .chain([octave])
+        .collect();
+    positions
+        .windows(2)
+        .map(|pair| {
+            let ((from_number, from_semitones), (to_number, to_semitones)) = (pair[0], pair[1]);
+            let step = (to_number >= from_number && to_semitones > from_semitones)
+                .then(|| {
+                    NotePitchInterval::from_number_and_semitones(
+                        to_number - from_number + 1,
+                        to_semitones - from_semitones,
+                    )
+                })
+                .flatten();
+            step.ok_or(format!(
+                "The degrees of {pattern} don't rise from the root to the octave one step at a time"
+            ))
+        })
+        .collect()
 }
 
+// every built-in kind, grouped by family.
+// the church modes come before major and minor, so the modes of a scale are named after them.
+static SCALE_KINDS: [ScaleKind; 48] = [
+    ScaleKind::Ionian,
+    ScaleKind::Dorian,
+    ScaleKind::Phrygian,
+    ScaleKind::Lydian,
+    ScaleKind::Mixolydian,
+    ScaleKind::Aeolian,
+    ScaleKind::Locrian,
+    ScaleKind::Major,
+    ScaleKind::Minor,
+    ScaleKind::MelodicMinor,
+    ScaleKind::DorianFlatTwo,
+    ScaleKind::LydianAugmented,
+    ScaleKind::LydianDominant,
+    ScaleKind::MixolydianFlatSix,
+    ScaleKind::LocrianSharpTwo,
+    ScaleKind::Altered,
+    ScaleKind::HarmonicMinor,
+    ScaleKind::LocrianSharpSix,
+    ScaleKind::IonianSharpFive,
+    ScaleKind::DorianSharpFour,
+    ScaleKind::PhrygianDominant,
+    ScaleKind::LydianSharpTwo,
+    ScaleKind::SuperLocrianDoubleFlatSeven,
+    ScaleKind::MajorPentatonic,
+    ScaleKind::MinorPentatonic,
+    ScaleKind::HalfWhole,
+    ScaleKind::WholeHalf,
+    ScaleKind::WholeTone,
+    ScaleKind::Augmented,
+    ScaleKind::MinorBlues,
+    ScaleKind::MajorBlues,
+    ScaleKind::BebopDominant,
+    ScaleKind::BebopMajor,
+    ScaleKind::BebopMinor,
+    ScaleKind::HungarianMinor,
+    ScaleKind::HungarianMajor,
+    ScaleKind::DoubleHarmonic,
+    ScaleKind::NeapolitanMajor,
+    ScaleKind::NeapolitanMinor,
+    ScaleKind::Enigmatic,
+    ScaleKind::Persian,
+    ScaleKind::Hirajoshi,
+    ScaleKind::InSen,
+    ScaleKind::Iwato,
+    ScaleKind::Kumoi,
+    ScaleKind::Pelog,
+    ScaleKind::Egyptian,
+    ScaleKind::Prometheus,
+];
+
 impl ScaleKind {
+    // the steps between neighbouring notes from the root up to the octave.
+    // built-in scales read them from constant tables, so this never allocates.
+    pub fn steps(&self) -> &[NotePitchInterval] {
+        use ScaleKind::*;
+        match self {
+            Ionian | Major => &steps::IONIAN,
+            Dorian => &steps::DORIAN,
+            Phrygian => &steps::PHRYGIAN,
+            Lydian => &steps::LYDIAN,
+            Mixolydian => &steps::MIXOLYDIAN,
+            Aeolian | Minor => &steps::AEOLIAN,
+            Locrian => &steps::LOCRIAN,
+            MelodicMinor => &steps::MELODIC_MINOR,
+            DorianFlatTwo => &steps::DORIAN_FLAT_TWO,
+            LydianAugmented => &steps::LYDIAN_AUGMENTED,
+            LydianDominant => &steps::LYDIAN_DOMINANT,
+            MixolydianFlatSix => &steps::MIXOLYDIAN_FLAT_SIX,
+            LocrianSharpTwo => &steps::LOCRIAN_SHARP_TWO,
+            Altered => &steps::ALTERED,
+            HarmonicMinor => &steps::HARMONIC_MINOR,
+            LocrianSharpSix => &steps::LOCRIAN_SHARP_SIX,
+            IonianSharpFive => &steps::IONIAN_SHARP_FIVE,
+            DorianSharpFour => &steps::DORIAN_SHARP_FOUR,
+            PhrygianDominant => &steps::PHRYGIAN_DOMINANT,
+            LydianSharpTwo => &steps::LYDIAN_SHARP_TWO,
+            SuperLocrianDoubleFlatSeven => &steps::SUPER_LOCRIAN_DOUBLE_FLAT_SEVEN,
+            MajorPentatonic => &steps::MAJOR_PENTATONIC,
+            MinorPentatonic => &steps::MINOR_PENTATONIC,
+            HalfWhole => &steps::HALF_WHOLE,
+            WholeHalf => &steps::WHOLE_HALF,
+            WholeTone => &steps::WHOLE_TONE,
+            Augmented => &steps::AUGMENTED,
+            MinorBlues => &steps::MINOR_BLUES,
+            MajorBlues => &steps::MAJOR_BLUES,
+            BebopDominant => &steps::BEBOP_DOMINANT,
+            BebopMajor => &steps::BEBOP_MAJOR,
+            BebopMinor => &steps::BEBOP_MINOR,
+            HungarianMinor => &steps::HUNGARIAN_MINOR,
+            HungarianMajor => &steps::HUNGARIAN_MAJOR,
+            DoubleHarmonic => &steps::DOUBLE_HARMONIC,
+            NeapolitanMajor => &steps::NEAPOLITAN_MAJOR,
+            NeapolitanMinor => &steps::NEAPOLITAN_MINOR,
+            Enigmatic => &steps::ENIGMATIC,
+            Persian => &steps::PERSIAN,
+            Hirajoshi => &steps::HIRAJOSHI,
+            InSen => &steps::IN_SEN,
+            Iwato => &steps::IWATO,
+            Kumoi => &steps::KUMOI,
+            Pelog => &steps::PELOG,
+            Egyptian => &steps::EGYPTIAN,
+            Prometheus => &steps::PROMETHEUS,
+            Custom(scale) => &scale.steps,
+        }
+    }
+
     pub fn intervals(&self, direction: ScaleDirection) -> Vec<NotePitchInterval> {
-        use NotePitchInterval::*;
+        self.intervals_iter(direction).collect()
+    }
+
+    // the intervals in the order they're played, like intervals but without allocating.
+    // descending, the steps are played from the octave down, and melodic minor comes down as natural minor.
+    pub fn intervals_iter(
+        &self,
+        direction: ScaleDirection,
+    ) -> impl ExactSizeIterator<Item = NotePitchInterval> + '_ {
         use ScaleDirection::*;
+        let (steps, reversed) = match (self, direction) {
+            (_, Ascending | AscendingDescending) => (self.steps(), false),
+            (ScaleKind::MelodicMinor, _) => (&steps::AEOLIAN[..], true),
+            _ => (self.steps(), true),
+        };
+        let length = steps.len();
+        (0..length).map(move |index| match reversed {
+            true => steps[length - 1 - index],
+            false => steps[index],
+        })
+    }
+
+    // every built-in kind, grouped by family
+    pub fn built_in() -> &'static [ScaleKind] {
+        &SCALE_KINDS
+    }
+
+    // the other names the scale is known by
+    pub fn aliases(&self) -> &'static [&'static str] {
         use ScaleKind::*;
+        match self {
+            Minor => &["natural minor"],
+            MajorPentatonic => &["pentatonic"],
+            DorianFlatTwo => &["Phrygian #6"],
+            LydianDominant => &["overtone", "acoustic", "Lydian b7"],
+            MixolydianFlatSix => &["Aeolian dominant", "Hindu"],
+            LocrianSharpTwo => &["half-diminished", "Aeolian b5"],
+            Altered => &["super Locrian", "diminished whole tone"],
+            DorianSharpFour => &["Romanian minor", "Ukrainian Dorian"],
+            PhrygianDominant => &["Spanish gypsy", "Freygish", "Phrygian major"],
+            HalfWhole => &["dominant diminished", "octatonic"],
+            WholeHalf => &["diminished"],
+            Augmented => &["augmented hexatonic", "symmetric augmented"],
+            MinorBlues => &["blues", "blues minor", "hexatonic blues"],
+            MajorBlues => &["blues major"],
+            BebopDominant => &["bebop", "Mixolydian bebop"],
+            BebopMajor => &["Ionian bebop"],
+            BebopMinor => &["Dorian bebop"],
+            HungarianMinor => &["gypsy minor", "double harmonic minor"],
+            HungarianMajor => &["Lydian dominant #2"],
+            DoubleHarmonic => &["Byzantine", "Arabic", "gypsy major"],
+            NeapolitanMajor => &["melodic minor b2"],
+            NeapolitanMinor => &["Neapolitan", "harmonic minor b2"],
+            Enigmatic => &["Verdi's enigmatic", "scala enigmatica"],
+            Persian => &["double harmonic b5"],
+            Hirajoshi => &["Japanese"],
+            InSen => &["In", "Sakura"],
+            Iwato => &["Iwato pentatonic"],
+            Kumoi => &["Kumoijoshi"],
+            Pelog => &["Pelog selisir"],
+            Egyptian => &["suspended pentatonic"],
+            Prometheus => &["mystic"],
+            _ => &[],
+        }
+    }
 
-        let mut result = match self {
-            Ionian | Major => vec![
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-            ],
-            Dorian => vec![
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-            ],
-            Phrygian => vec![
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-            ],
-            Lydian => vec![
-                MajorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-            ],
-            Mixolydian => vec![
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-            ],
-            Aeolian | Minor => vec![
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-            ],
-            Locrian => vec![
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
-                MajorSecond,
-                MajorSecond,
-            ],
-            MajorPentatonic => vec![
-                MajorSecond,
-                MajorSecond,
-                MinorThird,
-                MajorSecond,
-                MinorThird,
-            ],
-            MinorPentatonic => vec![
-                MinorThird,
-                MajorSecond,
-                MajorSecond,
-                MinorThird,
-                MajorSecond,
-            ],
-            HarmonicMinor => vec![
-                MajorSecond,
-                MinorSecond,
-                MajorSecond,
+    // the scale starting on the nth degree of this one, counting the root as 1.
+    // the mode is the built-in kind with the same steps when there is one, e.g. the 2nd mode of Ionian is Dorian
+    // and the 4th of melodic minor Lydian dominant, and otherwise a custom scale named after this one.
+    pub fn mode(&self, n: usize) -> Result<ScaleKind, String> {
+        let steps = self.steps();
+        if n == 0 || n > steps.len() {
+            return Err(format!(
+                "The {self} scale has {} modes, so it has no mode {n}",
+                steps.len()
+            ));
+        }
+        if n == 1 {
+            return Ok(self.clone());
+        }
+        let intervals = self.mode_intervals(n);
+        let semitones = |intervals: &[NotePitchInterval]| -> Vec<u8> {
+            intervals
+                .iter()
+                .map(|interval| interval.semitones())
+                .collect()
+        };
+        let built_in = SCALE_KINDS
+            .iter()
+            .find(|kind| semitones(kind.steps()) == semitones(&intervals));
+        Ok(match built_in {
+            Some(kind) => kind.clone(),
+            None => ScaleKind::Custom(CustomScale {
+                name: format!("mode {n} of {self}"),
+                steps: intervals,
+            }),
+        })
+    }
+
+    // the ascending steps rotated to start on the nth degree
+    fn mode_intervals(&self, n: usize) -> Vec<NotePitchInterval> {
+        let mut intervals = self.steps().to_vec();
+        intervals.rotate_left(n - 1);
+        intervals
+    }
+}
+
+impl Display for ScaleKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ScaleKind::*;
+        let name = match self {
+            Major => "major",
+            Minor => "minor",
+            MajorPentatonic => "major pentatonic",
+            MinorPentatonic => "minor pentatonic",
+            Ionian => "Ionian",
+            Dorian => "Dorian",
+            Phrygian => "Phrygian",
+            Lydian => "Lydian",
+            Mixolydian => "Mixolydian",
+            Aeolian => "Aeolian",
+            Locrian => "Locrian",
+            HarmonicMinor => "harmonic minor",
+            MelodicMinor => "melodic minor",
+            DorianFlatTwo => "Dorian b2",
+            LydianAugmented => "Lydian augmented",
+            LydianDominant => "Lydian dominant",
+            MixolydianFlatSix => "Mixolydian b6",
+            LocrianSharpTwo => "Locrian #2",
+            Altered => "altered",
+            LocrianSharpSix => "Locrian #6",
+            IonianSharpFive => "Ionian #5",
+            DorianSharpFour => "Dorian #4",
+            PhrygianDominant => "Phrygian dominant",
+            LydianSharpTwo => "Lydian #2",
+            SuperLocrianDoubleFlatSeven => "super Locrian bb7",
+            HalfWhole => "half-whole diminished",
+            WholeHalf => "whole-half diminished",
+            WholeTone => "whole tone",
+            Augmented => "augmented",
+            MinorBlues => "minor blues",
+            MajorBlues => "major blues",
+            BebopDominant => "bebop dominant",
+            BebopMajor => "bebop major",
+            BebopMinor => "bebop minor",
+            HungarianMinor => "Hungarian minor",
+            HungarianMajor => "Hungarian major",
+            DoubleHarmonic => "double harmonic",
+            NeapolitanMajor => "Neapolitan major",
+            NeapolitanMinor => "Neapolitan minor",
+            Enigmatic => "enigmatic",
+            Persian => "Persian",
+            Hirajoshi => "Hirajoshi",
+            InSen => "In-sen",
+            Iwato => "Iwato",
+            Kumoi => "Kumoi",
+            Pelog => "Pelog",
+            Egyptian => "Egyptian",
+            Prometheus => "Prometheus",
+            Custom(scale) => scale.name(),
+        };
+        write!(f, "{name}")
+    }
+}
+
+// looks a built-in scale up by its name or one of its aliases, ignoring case
+impl TryFrom<&str> for ScaleKind {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        let name = name.trim();
+        SCALE_KINDS
+            .iter()
+            .find(|kind| {
+                kind.to_string().eq_ignore_ascii_case(name)
+                    || kind
+                        .aliases()
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .cloned()
+            .ok_or(format!("{name} is not the name of a built-in scale"))
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod kind_test {
+    use super::*;
+    use ScaleKind::*;
+
+    #[test]
+    fn modes_of_major_are_church_modes() {
+        let modes: Vec<ScaleKind> = (1..=7).map(|n| Ionian.mode(n).unwrap()).collect();
+        assert_eq!(
+            modes,
+            [Ionian, Dorian, Phrygian, Lydian, Mixolydian, Aeolian, Locrian]
+        );
+        assert_eq!(Major.mode(1).unwrap(), Major);
+        assert_eq!(Dorian.mode(7).unwrap(), Ionian);
+    }
+
+    #[test]
+    fn modes_of_minor_scales() {
+        let modes: Vec<ScaleKind> = (2..=7).map(|n| MelodicMinor.mode(n).unwrap()).collect();
+        assert_eq!(
+            modes,
+            [
+                DorianFlatTwo,
+                LydianAugmented,
+                LydianDominant,
+                MixolydianFlatSix,
+                LocrianSharpTwo,
+                Altered
+            ]
+        );
+        let modes: Vec<ScaleKind> = (2..=7).map(|n| HarmonicMinor.mode(n).unwrap()).collect();
+        assert_eq!(
+            modes,
+            [
+                LocrianSharpSix,
+                IonianSharpFive,
+                DorianSharpFour,
+                PhrygianDominant,
+                LydianSharpTwo,
+                SuperLocrianDoubleFlatSeven
+            ]
+        );
+    }
+
+    #[test]
+    fn modes_of_other_scales() {
+        assert_eq!(MajorPentatonic.mode(5).unwrap(), MinorPentatonic);
+        assert_eq!(HalfWhole.mode(2).unwrap(), WholeHalf);
+        assert_eq!(WholeTone.mode(4).unwrap(), WholeTone);
+
+        let mode = Augmented.mode(2).unwrap();
+        assert_eq!(mode.to_string(), "mode 2 of augmented");
+        assert_eq!(
+            mode.intervals(ScaleDirection::Ascending)[0],
+            NotePitchInterval::MinorSecond
+        );
+    }
+
+    #[test]
+    fn built_in_scales_span_an_octave() {
+        for kind in ScaleKind::built_in() {
+            let semitones: u8 = kind
+                .intervals(ScaleDirection::Ascending)
+                .iter()
+                .map(|interval| interval.semitones())
+                .sum();
+            assert_eq!(semitones, 12, "{kind}");
+        }
+    }
+
+    #[test]
+    fn finds_scales_by_name_and_alias() {
+        for kind in ScaleKind::built_in() {
+            assert_eq!(
+                &ScaleKind::try_from(kind.to_string().as_str()).unwrap(),
+                kind
+            );
+            for alias in kind.aliases() {
+                assert_eq!(&ScaleKind::try_from(*alias).unwrap(), kind, "{alias}");
+            }
+        }
+        assert_eq!(
+            ScaleKind::try_from("Spanish Gypsy").unwrap(),
+            PhrygianDominant
+        );
+        assert_eq!(ScaleKind::try_from(" blues ").unwrap(), MinorBlues);
+        assert_eq!(ScaleKind::try_from("blues major").unwrap(), MajorBlues);
+        assert_eq!(ScaleKind::try_from("neapolitan").unwrap(), NeapolitanMinor);
+        assert_eq!(
+            ScaleKind::try_from("melodic minor b2").unwrap(),
+            NeapolitanMajor
+        );
+        assert_eq!(ScaleKind::try_from("scala enigmatica").unwrap(), Enigmatic);
+        assert_eq!(ScaleKind::try_from("double harmonic b5").unwrap(), Persian);
+        assert_eq!(
+            ScaleKind::try_from("Lydian dominant #2").unwrap(),
+            HungarianMajor
+        );
+        assert_eq!(ScaleKind::try_from("Iwato pentatonic").unwrap(), Iwato);
+        assert!(ScaleKind::try_from("Lydian flat nine").is_err());
+    }
+
+    #[test]
+    fn modes_of_world_scales() {
+        assert_eq!(DoubleHarmonic.mode(4).unwrap(), HungarianMinor);
+        assert_eq!(Hirajoshi.mode(1).unwrap(), Hirajoshi);
+    }
+
+    #[test]
+    fn rejects_missing_modes() {
+        assert!(Ionian.mode(0).is_err());
+        assert!(Ionian.mode(8).is_err());
+        assert!(WholeTone.mode(7).is_err());
+    }
+
+    #[test]
+    fn step_tables_match_their_degrees() {
+        let cases = [
+            (MinorBlues, "1 b3 4 b5 5 b7"),
+            (MajorBlues, "1 2 b3 3 5 6"),
+            (BebopDominant, "1 2 3 4 5 6 b7 7"),
+            (BebopMajor, "1 2 3 4 5 #5 6 7"),
+            (BebopMinor, "1 2 b3 3 4 5 6 b7"),
+            (HungarianMinor, "1 2 b3 #4 5 b6 7"),
+            (HungarianMajor, "1 #2 3 #4 5 6 b7"),
+            (DoubleHarmonic, "1 b2 3 4 5 b6 7"),
+            (NeapolitanMajor, "1 b2 b3 4 5 6 7"),
+            (NeapolitanMinor, "1 b2 b3 4 5 b6 7"),
+            (Enigmatic, "1 b2 3 #4 #5 #6 7"),
+            (Persian, "1 b2 3 4 b5 b6 7"),
+            (Hirajoshi, "1 2 b3 5 b6"),
+            (InSen, "1 b2 4 5 b7"),
+            (Iwato, "1 b2 4 b5 b7"),
+            (Kumoi, "1 2 b3 5 6"),
+            (Pelog, "1 b2 b3 5 b6"),
+            (Egyptian, "1 2 4 5 b7"),
+            (Prometheus, "1 2 3 #4 6 b7"),
+            (Dorian, "1 2 b3 4 5 6 b7"),
+            (Altered, "1 b2 b3 b4 b5 b6 b7"),
+            (PhrygianDominant, "1 b2 3 4 5 b6 b7"),
+        ];
+        for (kind, degrees) in cases {
+            let custom = CustomScale::from_degrees("degrees", degrees).unwrap();
+            assert_eq!(kind.steps(), custom.steps(), "{kind}");
+        }
+    }
+
+    #[test]
+    fn iterates_intervals_in_either_direction() {
+        use NotePitchInterval::*;
+        let descending: Vec<NotePitchInterval> = MelodicMinor
+            .intervals_iter(ScaleDirection::Descending)
+            .collect();
+        assert_eq!(
+            descending,
+            [
                 MajorSecond,
-                MinorSecond,
-                AugmentedSecond,
-                MinorSecond,
-            ],
-            MelodicMinor => vec![
                 MajorSecond,
                 MinorSecond,
                 MajorSecond,
                 MajorSecond,
-                MajorSecond,
-                MajorSecond,
                 MinorSecond,
-            ],
-            // HalfWhole => vec![
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            // ],
-            // WholeHalf => vec![
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            //     MajorSecond,
-            //     MinorSecond,
-            // ],
-            // WholeTone => vec![MajorSecond; 6],
-        };
-        match direction {
-            Descending | DescendingAscending => {
-                result = match self {
-                    MelodicMinor => Aeolian.intervals(Descending),
-                    _ => result.into_iter().rev().collect(),
-                }
-            }
-            _ => {}
-        }
-
-        result
+                MajorSecond
+            ]
+        );
+        assert_eq!(
+            Hirajoshi.intervals_iter(ScaleDirection::Descending).len(),
+            5
+        );
+        assert_eq!(
+            Kumoi.intervals(ScaleDirection::AscendingDescending),
+            Kumoi.steps()
+        );
     }
 }
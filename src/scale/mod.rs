@@ -2,4 +2,19 @@ mod scale;
 pub use scale::{Scale, ScaleDirection};
 
 mod kind;
-pub use kind::ScaleKind;
+mod steps;
+pub use kind::{CustomScale, ScaleKind};
+
+mod identify;
+pub use identify::ScaleCandidate;
+
+mod degree;
+pub use degree::ScaleDegree;
+
+mod diatonic;
+
+mod exercise;
+pub use exercise::ScalePattern;
+
+mod quantize;
+pub use quantize::QuantizeRounding;
@@ -0,0 +1,239 @@
+use crate::chord::Chord;
+use crate::note::{Note, Pitch};
+
+use super::Scale;
+
+// which scale tone a pitch outside the scale snaps to.
+// Nearest sends pitches halfway between two scale tones up, and PreferChordTones sends them to the one that's
+// a tone of the chord, or up when both or neither are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuantizeRounding {
+    Up,
+    Down,
+    Nearest,
+    PreferChordTones(Chord),
+}
+
+impl Scale {
+    // the scale tone for a MIDI note number, where C4 is 60, spelled the way the scale spells it.
+    // pitches already in the scale stay where they are.
+    // fails for numbers so far out that the scale tones around them have no octave number.
+    pub fn quantize_midi(
+        &self,
+        midi_number: i16,
+        rounding: &QuantizeRounding,
+    ) -> Result<Pitch, String> {
+        self.quantize(midi_number as f64, rounding)
+            .ok_or(format!("MIDI note {midi_number} is out of range"))
+    }
+
+    // the scale tone for a frequency in hertz, with A4 tuned to 440 Hz.
+    // frequencies between two semitones snap to whichever scale tone is closer in cents.
+    pub fn quantize_frequency(
+        &self,
+        frequency: f64,
+        rounding: &QuantizeRounding,
+    ) -> Result<Pitch, String> {
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return Err(format!("{frequency} Hz is not a valid frequency"));
+        }
+        let midi_number = 69.0 + 12.0 * (frequency / 440.0).log2();
+        self.quantize(midi_number, rounding)
+            .ok_or(format!("{frequency} Hz is out of range"))
+    }
+
+    // the scale tone for a note, e.g. F# in C major pentatonic snaps down to E or up to G.
+    // notes enharmonic to a scale tone take its spelling, so Gb in D major is F#.
+    pub fn quantize_note(&self, note: &Note, rounding: &QuantizeRounding) -> Note {
+        if self.contains(note) {
+            return *note;
+        }
+        self.quantize(Pitch::new(*note, 4).midi_number() as f64, rounding)
+            .expect("the scale tones around octave 4 have octave numbers")
+            .note()
+    }
+
+    // None when the scale tone the rounding asks for has no octave number
+    fn quantize(&self, midi_number: f64, rounding: &QuantizeRounding) -> Option<Pitch> {
+        let mut below: Option<Pitch> = None;
+        let mut above: Option<Pitch> = None;
+        for &degree in self.degrees() {
+            let (lower, upper) = surrounding(degree, midi_number);
+            if let Some(lower) = lower {
+                if below.is_none_or(|pitch| lower.midi_number() > pitch.midi_number()) {
+                    below = Some(lower);
+                }
+            }
+            if let Some(upper) = upper {
+                if above.is_none_or(|pitch| upper.midi_number() < pitch.midi_number()) {
+                    above = Some(upper);
+                }
+            }
+        }
+        let exact = [below, above]
+            .into_iter()
+            .flatten()
+            .find(|pitch| pitch.midi_number() as f64 == midi_number);
+        if exact.is_some() {
+            return exact;
+        }
+
+        let (below, above) = match rounding {
+            QuantizeRounding::Up => return above,
+            QuantizeRounding::Down => return below,
+            _ => (below?, above?),
+        };
+        let to_below = midi_number - below.midi_number() as f64;
+        let to_above = above.midi_number() as f64 - midi_number;
+        let result = match rounding {
+            QuantizeRounding::PreferChordTones(chord) if to_below == to_above => {
+                let is_chord_tone = |pitch: Pitch| {
+                    chord
+                        .notes_iter()
+                        .any(|tone| tone.is_enharmonic(&pitch.note()))
+                };
+                match (is_chord_tone(below), is_chord_tone(above)) {
+                    (true, false) => below,
+                    _ => above,
+                }
+            }
+            _ if to_below < to_above => below,
+            _ => above,
+        };
+        Some(result)
+    }
+}
+
+// the highest pitch of the note at or below the MIDI number, and the lowest one at or above it.
+// either is None when it would need an octave number that doesn't fit in an i8.
+fn surrounding(note: Note, midi_number: f64) -> (Option<Pitch>, Option<Pitch>) {
+    let octave = ((midi_number / 12.0).floor() - 1.0).clamp(i8::MIN as f64, i8::MAX as f64);
+    let mut pitch = Pitch::new(note, octave as i8);
+    while pitch.midi_number() as f64 > midi_number {
+        match pitch.checked_by_octaves(-1) {
+            Some(lower) => pitch = lower,
+            None => return (None, Some(pitch)),
+        }
+    }
+    while (pitch.midi_number() + 12) as f64 <= midi_number {
+        match pitch.checked_by_octaves(1) {
+            Some(higher) => pitch = higher,
+            None => return (Some(pitch), None),
+        }
+    }
+    match pitch.midi_number() as f64 == midi_number {
+        true => (Some(pitch), Some(pitch)),
+        false => (Some(pitch), pitch.checked_by_octaves(1)),
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod quantize_test {
+    use super::*;
+    use crate::chord::ChordQuality;
+    use crate::scale::{ScaleDirection, ScaleKind};
+    use QuantizeRounding::*;
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn pitch(name: &str) -> Pitch {
+        Pitch::try_from(name).unwrap()
+    }
+
+    fn scale(root: &str, kind: ScaleKind) -> Scale {
+        Scale::new(note(root), kind, ScaleDirection::Ascending)
+    }
+
+    #[test]
+    fn snaps_midi_numbers_to_the_scale() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert_eq!(c_major.quantize_midi(60, &Nearest), Ok(pitch("C4")));
+        assert_eq!(c_major.quantize_midi(61, &Up), Ok(pitch("D4")));
+        assert_eq!(c_major.quantize_midi(61, &Down), Ok(pitch("C4")));
+        assert_eq!(c_major.quantize_midi(61, &Nearest), Ok(pitch("D4")));
+
+        let pentatonic = scale("C", ScaleKind::MajorPentatonic);
+        assert_eq!(pentatonic.quantize_midi(65, &Nearest), Ok(pitch("E4")));
+        assert_eq!(pentatonic.quantize_midi(71, &Nearest), Ok(pitch("C5")));
+        assert_eq!(pentatonic.quantize_midi(71, &Down), Ok(pitch("A4")));
+    }
+
+    #[test]
+    fn spells_pitches_the_way_the_scale_does() {
+        let e_flat_major = scale("Eb", ScaleKind::Major);
+        assert_eq!(e_flat_major.quantize_midi(68, &Nearest), Ok(pitch("Ab4")));
+        assert_eq!(e_flat_major.quantize_midi(71, &Up), Ok(pitch("C5")));
+
+        let c_sharp_major = scale("C#", ScaleKind::Major);
+        assert_eq!(c_sharp_major.quantize_midi(60, &Nearest), Ok(pitch("B#3")));
+        assert_eq!(c_sharp_major.quantize_midi(64, &Down), Ok(pitch("D#4")));
+    }
+
+    #[test]
+    fn prefers_chord_tones_on_ties() {
+        let pentatonic = scale("C", ScaleKind::MajorPentatonic);
+        let a_minor = Chord::new(note("A"), ChordQuality::Minor);
+        let c_major = Chord::new(note("C"), ChordQuality::Major);
+        let e_major = Chord::new(note("E"), ChordQuality::Major);
+        // G#4 lies halfway between G4 and A4
+        assert_eq!(
+            pentatonic.quantize_midi(68, &PreferChordTones(a_minor.clone())),
+            Ok(pitch("A4"))
+        );
+        assert_eq!(
+            pentatonic.quantize_midi(68, &PreferChordTones(c_major)),
+            Ok(pitch("G4"))
+        );
+        assert_eq!(
+            pentatonic.quantize_midi(68, &PreferChordTones(e_major)),
+            Ok(pitch("A4"))
+        );
+        // closer tones still win
+        assert_eq!(
+            pentatonic.quantize_midi(66, &PreferChordTones(a_minor)),
+            Ok(pitch("G4"))
+        );
+    }
+
+    #[test]
+    fn snaps_frequencies_to_the_scale() {
+        let a_minor = scale("A", ScaleKind::Minor);
+        assert_eq!(a_minor.quantize_frequency(440.0, &Nearest), Ok(pitch("A4")));
+        assert_eq!(a_minor.quantize_frequency(452.0, &Nearest), Ok(pitch("A4")));
+        assert_eq!(a_minor.quantize_frequency(470.0, &Nearest), Ok(pitch("B4")));
+        assert_eq!(a_minor.quantize_frequency(261.0, &Down), Ok(pitch("B3")));
+        assert_eq!(a_minor.quantize_frequency(262.0, &Down), Ok(pitch("C4")));
+        assert!(a_minor.quantize_frequency(0.0, &Nearest).is_err());
+        assert!(a_minor.quantize_frequency(f64::NAN, &Nearest).is_err());
+        assert!(a_minor.quantize_frequency(1e300, &Nearest).is_err());
+        assert!(a_minor.quantize_frequency(1e-300, &Nearest).is_err());
+    }
+
+    #[test]
+    fn fails_outside_the_range_of_pitches() {
+        let c_major = scale("C", ScaleKind::Major);
+        assert!(c_major.quantize_midi(i16::MAX, &Nearest).is_err());
+        assert!(c_major.quantize_midi(i16::MIN, &Nearest).is_err());
+        // the octave numbers stop at 127, so nothing lies above B127
+        assert_eq!(c_major.quantize_midi(1548, &Down), Ok(pitch("B127")));
+        assert!(c_major.quantize_midi(1548, &Up).is_err());
+        assert!(c_major.quantize_midi(1548, &Nearest).is_err());
+        assert_eq!(c_major.quantize_midi(-1525, &Up), Ok(pitch("C-128")));
+    }
+
+    #[test]
+    fn snaps_notes_to_the_scale() {
+        let d_major = scale("D", ScaleKind::Major);
+        assert_eq!(d_major.quantize_note(&note("F#"), &Nearest), note("F#"));
+        assert_eq!(d_major.quantize_note(&note("Gb"), &Nearest), note("F#"));
+        assert_eq!(d_major.quantize_note(&note("F"), &Down), note("E"));
+        assert_eq!(d_major.quantize_note(&note("Bb"), &Up), note("B"));
+        assert_eq!(d_major.quantize_note(&note("Cb"), &Nearest), note("B"));
+    }
+}
@@ -1,15 +1,28 @@
 #![allow(dead_code)]
-use crate::note::Note;
+use std::fmt::{Display, Formatter};
+
+use crate::chord::{Chord, ChordAlteration, ChordQuality, ChordSeventh};
+use crate::fixed::FixedVec;
+use crate::note::{Note, NotePitchInterval};
 
 use super::ScaleKind;
 
+// every step of a scale rises within the octave, so no scale has more than twelve degrees
+const MAX_DEGREES: usize = 12;
+// a scale played up and back down has every degree twice, plus the octave
+const MAX_NOTES: usize = 2 * MAX_DEGREES + 1;
+
+// the notes are kept in fixed size lists, so building a scale doesn't allocate
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Scale {
     direction: ScaleDirection,
     kind: ScaleKind,
-    notes: Vec<Note>,
+    notes: FixedVec<Note, MAX_NOTES>,
+    // the ascending form of the scale from the root, without the octave
+    degrees: FixedVec<Note, MAX_DEGREES>,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ScaleDirection {
     Ascending,
     Descending,
@@ -18,66 +31,199 @@ pub enum ScaleDirection {
 }
 
 impl Scale {
+    // panics when a note of the scale would need more than two accidentals, e.g. the altered scale on Fb.
+    // try_new returns an error instead.
     pub fn new(root_note: Note, kind: ScaleKind, direction: ScaleDirection) -> Self {
-        Self {
-            notes: Scale::notes_from_root(root_note, kind, direction),
-            kind,
-            direction,
+        Scale::try_new(root_note, kind, direction).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_new(
+        root_note: Note,
+        kind: ScaleKind,
+        direction: ScaleDirection,
+    ) -> Result<Self, String> {
+        match Scale::spell(root_note, &kind, direction) {
+            Some((notes, degrees)) => Ok(Self {
+                notes,
+                degrees,
+                kind,
+                direction,
+            }),
+            None => Err(format!(
+                "The {kind} scale on {root_note} can't be spelled with at most two accidentals"
+            )),
         }
     }
 
-    pub fn kind(&self) -> ScaleKind {
-        self.kind
+    // like new, but returns None instead of panicking when a note would need more than two accidentals
+    pub(crate) fn checked_new(
+        root_note: Note,
+        kind: ScaleKind,
+        direction: ScaleDirection,
+    ) -> Option<Self> {
+        Scale::try_new(root_note, kind, direction).ok()
+    }
+
+    // the notes in the given direction and the ascending degrees
+    fn spell(
+        root_note: Note,
+        kind: &ScaleKind,
+        direction: ScaleDirection,
+    ) -> Option<(FixedVec<Note, MAX_NOTES>, FixedVec<Note, MAX_DEGREES>)> {
+        let ascending = Scale::notes_from_root(root_note, kind, ScaleDirection::Ascending)?;
+        let degrees = FixedVec::from_slice(&ascending[..ascending.len() - 1])?;
+        Some((Scale::notes_from_root(root_note, kind, direction)?, degrees))
+    }
+
+    pub fn kind(&self) -> &ScaleKind {
+        &self.kind
+    }
+
+    pub fn direction(&self) -> ScaleDirection {
+        self.direction
+    }
+
+    pub fn root(&self) -> Note {
+        self.degrees[0]
     }
 
     pub fn notes(self) -> Vec<Note> {
-        self.notes
+        self.notes.to_vec()
+    }
+
+    // the notes in the order they're played, like notes but without consuming the scale
+    pub fn tones(&self) -> &[Note] {
+        &self.notes
+    }
+
+    // every degree of the scale once, in ascending order from the root whatever the direction
+    pub fn degrees(&self) -> &[Note] {
+        &self.degrees
     }
 
+    // the notes separated by spaces, the same as the scale's Display
     pub fn print(&self) -> String {
-        let mut result = String::with_capacity(self.notes.len() * 2 - 1); // minimum length is twice the amount - 1 space at the end
-        let mut index = 0;
-        for &note in self.notes.iter() {
-            result.push_str(&note.to_string());
-            if index < self.notes.len() - 1 {
-                result.push(' ');
-                index += 1;
-            }
+        self.to_string()
+    }
+
+    // the triad built on each degree of the scale by stacking the scale's own thirds, one entry per degree.
+    // e.g. C major gives C, Dm, Em, F, G, Am and Bdim, and D Hungarian minor has E(b5) on its second degree.
+    // the ascending form of the scale is used, and scales without seven notes are an error.
+    // a degree is None when its third is neither minor nor major, or its fifth isn't diminished, perfect or
+    // augmented, like the diminished third of F# Ab C in C Hungarian minor. this never happens in the
+    // major, melodic minor and harmonic minor scales or their modes.
+    pub fn triads(&self) -> Result<Vec<Option<Chord>>, String> {
+        let degrees = self.heptatonic_degrees()?;
+        Ok((0..degrees.len())
+            .map(|index| stacked_triad(&degrees, index))
+            .collect())
+    }
+
+    // the seventh chord built on each degree of the scale by stacking the scale's own thirds, one entry per degree.
+    // e.g. C major gives Cmaj7, Dm7, Em7, Fmaj7, G7, Am7 and Bm7b5.
+    // scales without seven notes are an error, like for triads. a degree is None when its triad is, or when
+    // its seventh is neither minor nor major, except for the diminished seventh on a diminished triad,
+    // like the diminished seventh Db above E G B in C double harmonic.
+    // the major, melodic minor and harmonic minor scales and their modes have a seventh chord on every degree.
+    pub fn seventh_chords(&self) -> Result<Vec<Option<Chord>>, String> {
+        let degrees = self.heptatonic_degrees()?;
+        Ok((0..degrees.len())
+            .map(|index| {
+                use NotePitchInterval::*;
+                let triad = stacked_triad(&degrees, index)?;
+                let seventh = match triad.root().interval_to(&degrees[(index + 6) % 7])? {
+                    MajorSeventh => ChordSeventh::Major,
+                    MinorSeventh => ChordSeventh::Minor,
+                    // only the diminished triad takes a diminished seventh, so E G B Db isn't a seventh chord
+                    DiminishedSeventh if triad.quality() == ChordQuality::Diminished => {
+                        ChordSeventh::Diminished
+                    }
+                    _ => return None,
+                };
+                Some(triad.with_seventh(seventh))
+            })
+            .collect())
+    }
+
+    // the seven degrees of the ascending form of the scale, starting from the root
+    pub(crate) fn heptatonic_degrees(&self) -> Result<Vec<Note>, String> {
+        if self.degrees.len() != 7 {
+            return Err(format!(
+                "Only heptatonic scales can be harmonized in thirds, but this scale has {} notes",
+                self.degrees.len()
+            ));
         }
-        result
+        Ok(self.degrees.to_vec())
     }
 
-    fn notes_from_root(root_note: Note, kind: ScaleKind, direction: ScaleDirection) -> Vec<Note> {
+    fn notes_from_root(
+        root_note: Note,
+        kind: &ScaleKind,
+        direction: ScaleDirection,
+    ) -> Option<FixedVec<Note, MAX_NOTES>> {
         use ScaleDirection::*;
-        let mut result: Vec<Note> = vec![root_note];
+        let mut result = FixedVec::new(root_note);
+        result.push(root_note)?;
 
-        for interval in kind.intervals(direction) {
+        for interval in kind.intervals_iter(direction) {
+            let last = result.last()?;
             let next = match direction {
-                Ascending | AscendingDescending => {
-                    result.last().unwrap().by_interval_ascending(interval)
-                }
+                Ascending | AscendingDescending => last.checked_by_interval_ascending(interval),
                 Descending | DescendingAscending => {
-                    result.last().unwrap().by_interval_descending(interval)
+                    last.checked_by_interval_ascending(interval.invert())
                 }
             };
-            result.push(next);
+            result.push(next?)?;
         }
-        match direction {
-            AscendingDescending => {
-                let second_half = Scale::notes_from_root(root_note, kind, Descending);
-                result.extend_from_slice(&second_half[1..]);
-            }
-            DescendingAscending => {
-                let second_half = Scale::notes_from_root(root_note, kind, Ascending);
-                result.extend_from_slice(&second_half[1..]);
-            }
-            _ => {}
+        let second_half = match direction {
+            AscendingDescending => Scale::notes_from_root(root_note, kind, Descending)?,
+            DescendingAscending => Scale::notes_from_root(root_note, kind, Ascending)?,
+            _ => return Some(result),
+        };
+        for &note in &second_half[1..] {
+            result.push(note)?;
         }
 
-        result
+        Some(result)
+    }
+}
+
+impl Display for Scale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, note) in self.notes.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{note}")?;
+        }
+        Ok(())
     }
 }
 
+// the triad of the thirds stacked on a degree, with the fifth altered when it doesn't fit the third,
+// e.g. the major third and diminished fifth of G B Db make G(b5)
+fn stacked_triad(degrees: &[Note], index: usize) -> Option<Chord> {
+    use ChordQuality::*;
+    use NotePitchInterval::*;
+    let root = degrees[index];
+    let third = root.interval_to(&degrees[(index + 2) % degrees.len()])?;
+    let fifth = root.interval_to(&degrees[(index + 4) % degrees.len()])?;
+    let (quality, alteration) = match (third, fifth) {
+        (MajorThird, PerfectFifth) => (Major, None),
+        (MinorThird, PerfectFifth) => (Minor, None),
+        (MinorThird, DiminishedFifth) => (Diminished, None),
+        (MajorThird, AugmentedFifth) => (Augmented, None),
+        (MajorThird, DiminishedFifth) => (Major, Some(ChordAlteration::FlatFifth)),
+        (MinorThird, AugmentedFifth) => (Minor, Some(ChordAlteration::SharpFifth)),
+        _ => return None,
+    };
+    let triad = Chord::new(root, quality);
+    Some(match alteration {
+        Some(alteration) => triad.with_alteration(alteration),
+        None => triad,
+    })
+}
+
 ////////////////
 // UNIT TESTS //
 ////////////////
@@ -502,6 +648,102 @@ mod scale_print_test {
             ascending_scale_test_case("G", MelodicMinor, "G A Bb C D E F# G");
             ascending_scale_test_case("G#", MelodicMinor, "G# A# B C# D# E# F## G#");
         }
+
+        #[test]
+        fn creates_ascending_lydian_dominant_scale() {
+            ascending_scale_test_case("C", LydianDominant, "C D E F# G A Bb C");
+            ascending_scale_test_case("G", LydianDominant, "G A B C# D E F G");
+            ascending_scale_test_case("Bb", LydianDominant, "Bb C D E F G Ab Bb");
+            ascending_scale_test_case("F#", LydianDominant, "F# G# A# B# C# D# E F#");
+        }
+
+        #[test]
+        fn creates_ascending_altered_scale() {
+            ascending_scale_test_case("G", Altered, "G Ab Bb Cb Db Eb F G");
+            ascending_scale_test_case("B", Altered, "B C D Eb F G A B");
+            ascending_scale_test_case("C#", Altered, "C# D E F G A B C#");
+            ascending_scale_test_case("Eb", Altered, "Eb Fb Gb Abb Bbb Cb Db Eb");
+        }
+
+        #[test]
+        fn creates_ascending_melodic_minor_modes() {
+            ascending_scale_test_case("D", DorianFlatTwo, "D Eb F G A B C D");
+            ascending_scale_test_case("Eb", LydianAugmented, "Eb F G A B C D Eb");
+            ascending_scale_test_case("G", MixolydianFlatSix, "G A B C D Eb F G");
+            ascending_scale_test_case("A", LocrianSharpTwo, "A B C D Eb F G A");
+        }
+
+        #[test]
+        fn creates_ascending_harmonic_minor_modes() {
+            ascending_scale_test_case("B", LocrianSharpSix, "B C D E F G# A B");
+            ascending_scale_test_case("C", IonianSharpFive, "C D E F G# A B C");
+            ascending_scale_test_case("D", DorianSharpFour, "D E F G# A B C D");
+            ascending_scale_test_case("E", PhrygianDominant, "E F G# A B C D E");
+            ascending_scale_test_case("F", LydianSharpTwo, "F G# A B C D E F");
+            ascending_scale_test_case("G#", SuperLocrianDoubleFlatSeven, "G# A B C D E F G#");
+        }
+
+        #[test]
+        fn creates_ascending_blues_scales() {
+            ascending_scale_test_case("C", MinorBlues, "C Eb F Gb G Bb C");
+            ascending_scale_test_case("A", MinorBlues, "A C D Eb E G A");
+            ascending_scale_test_case("C", MajorBlues, "C D Eb E G A C");
+            ascending_scale_test_case("G", MajorBlues, "G A Bb B D E G");
+        }
+
+        #[test]
+        fn creates_ascending_bebop_scales() {
+            ascending_scale_test_case("G", BebopDominant, "G A B C D E F F# G");
+            ascending_scale_test_case("C", BebopMajor, "C D E F G G# A B C");
+            ascending_scale_test_case("D", BebopMinor, "D E F F# G A B C D");
+        }
+
+        #[test]
+        fn creates_ascending_world_scales() {
+            ascending_scale_test_case("A", HungarianMinor, "A B C D# E F G# A");
+            ascending_scale_test_case("C", HungarianMajor, "C D# E F# G A Bb C");
+            ascending_scale_test_case("C", DoubleHarmonic, "C Db E F G Ab B C");
+            ascending_scale_test_case("C", NeapolitanMajor, "C Db Eb F G A B C");
+            ascending_scale_test_case("C", NeapolitanMinor, "C Db Eb F G Ab B C");
+            ascending_scale_test_case("C", Enigmatic, "C Db E F# G# A# B C");
+            ascending_scale_test_case("C", Persian, "C Db E F Gb Ab B C");
+            ascending_scale_test_case("A", Hirajoshi, "A B C E F A");
+            ascending_scale_test_case("E", InSen, "E F A B D E");
+            ascending_scale_test_case("B", Iwato, "B C E F A B");
+            ascending_scale_test_case("D", Kumoi, "D E F A B D");
+            ascending_scale_test_case("E", Pelog, "E F G B C E");
+            ascending_scale_test_case("C", Egyptian, "C D F G Bb C");
+            ascending_scale_test_case("C", Prometheus, "C D E F# A Bb C");
+        }
+
+        #[test]
+        fn creates_ascending_half_whole_scale() {
+            ascending_scale_test_case("C", HalfWhole, "C Db Eb E F# G A Bb C");
+            ascending_scale_test_case("G", HalfWhole, "G Ab Bb B C# D E F G");
+            ascending_scale_test_case("Bb", HalfWhole, "Bb Cb Db D E F G Ab Bb");
+            ascending_scale_test_case("F#", HalfWhole, "F# G A A# B# C# D# E F#");
+        }
+
+        #[test]
+        fn creates_ascending_whole_half_scale() {
+            ascending_scale_test_case("C", WholeHalf, "C D Eb F Gb Ab A B C");
+            ascending_scale_test_case("A", WholeHalf, "A B C D Eb F F# G# A");
+            ascending_scale_test_case("Eb", WholeHalf, "Eb F Gb Ab Bbb Cb C D Eb");
+        }
+
+        #[test]
+        fn creates_ascending_whole_tone_scale() {
+            ascending_scale_test_case("C", WholeTone, "C D E F# G# A# C");
+            ascending_scale_test_case("Db", WholeTone, "Db Eb F G A B Db");
+            ascending_scale_test_case("F#", WholeTone, "F# G# A# B# C## D## F#");
+        }
+
+        #[test]
+        fn creates_ascending_augmented_scale() {
+            ascending_scale_test_case("C", Augmented, "C D# E G Ab B C");
+            ascending_scale_test_case("E", Augmented, "E F## G# B C D# E");
+            ascending_scale_test_case("Ab", Augmented, "Ab B C Eb Fb G Ab");
+        }
     }
 
     mod descending_scales {
@@ -913,6 +1155,14 @@ mod scale_print_test {
             descending_test_case("G", MelodicMinor, "G F Eb D C Bb A G");
             descending_test_case("G#", MelodicMinor, "G# F# E D# C# B A# G#");
         }
+
+        #[test]
+        fn creates_descending_symmetric_scales() {
+            descending_test_case("C", HalfWhole, "C Bb A G F# E Eb Db C");
+            descending_test_case("C", WholeHalf, "C B A Ab Gb F Eb D C");
+            descending_test_case("C", WholeTone, "C A# G# F# E D C");
+            descending_test_case("C", Augmented, "C B Ab G E D# C");
+        }
     }
 
     mod ascending_descending_scales {
@@ -3000,4 +3250,239 @@ mod scale_print_test {
             );
         }
     }
+
+    mod custom_scales {
+        use super::*;
+        use crate::scale::CustomScale;
+
+        fn custom(steps: &str) -> ScaleKind {
+            Custom(CustomScale::new("custom", steps).unwrap())
+        }
+
+        fn custom_degrees(degrees: &str) -> ScaleKind {
+            Custom(CustomScale::from_degrees("custom", degrees).unwrap())
+        }
+
+        #[test]
+        fn reads_every_pattern_notation() {
+            test_case("C", custom("W W H W W W H"), Ascending, "C D E F G A B C");
+            test_case("C", custom("2 2 1 2 2 2 1"), Ascending, "C D E F G A B C");
+            test_case(
+                "C",
+                custom_degrees("1 2 b3 4 5 b6 7"),
+                Ascending,
+                "C D Eb F G Ab B C",
+            );
+            test_case(
+                "D",
+                custom("h a h w h a h"),
+                Ascending,
+                "D Eb F# G A Bb C# D",
+            );
+        }
+
+        #[test]
+        fn works_in_every_direction() {
+            let hungarian_minor = custom_degrees("1 2 b3 #4 5 b6 7");
+            test_case(
+                "A",
+                hungarian_minor.clone(),
+                Descending,
+                "A G# F E D# C B A",
+            );
+            test_case(
+                "A",
+                hungarian_minor,
+                AscendingDescending,
+                "A B C D# E F G# A G# F E D# C B A",
+            );
+        }
+
+        #[test]
+        fn spells_scales_without_seven_notes_from_the_root() {
+            test_case("C", custom("3 2 1 1 3 2"), Ascending, "C Eb F F# G Bb C");
+            test_case("C", custom_degrees("1 b3 3 5 6"), Ascending, "C Eb E G A C");
+            test_case(
+                "C",
+                custom_degrees("1 b3 3 5 6"),
+                Descending,
+                "C A G E Eb C",
+            );
+            test_case("E", custom("W W A W A"), Ascending, "E F# G# B C# E");
+        }
+
+        #[test]
+        fn reads_numbers_as_steps_unless_told_they_are_degrees() {
+            test_case("C", custom("1 3 8"), Ascending, "C Db E C");
+            test_case("C", custom("1 2 4 5"), Ascending, "C Db Eb G C");
+            test_case("C", custom_degrees("1 2 4 5"), Ascending, "C D F G C");
+        }
+
+        #[test]
+        fn keeps_its_name() {
+            let scale = CustomScale::new("Hirajoshi", "2 1 4 1 4").unwrap();
+            assert_eq!(scale.name(), "Hirajoshi");
+            assert_eq!(scale.steps().len(), 5);
+        }
+
+        #[test]
+        fn rejects_invalid_patterns() {
+            assert!(CustomScale::new("empty", "").is_err());
+            assert!(CustomScale::new("short", "W W H W W W").is_err());
+            assert!(CustomScale::new("unknown step", "W W X W W W H").is_err());
+            assert!(CustomScale::new("wide step", "2 2 1 4 1 1 1").is_err());
+            assert!(CustomScale::new("degrees", "1 2 b3 4 5 b6 7").is_err());
+            assert!(CustomScale::from_degrees("empty", "").is_err());
+            assert!(CustomScale::from_degrees("steps", "W W H W W W H").is_err());
+            assert!(CustomScale::from_degrees("unknown degree", "1 2 b3 9").is_err());
+            assert!(CustomScale::from_degrees("falling degrees", "1 b3 #2 5").is_err());
+            assert!(CustomScale::from_degrees("no root", "2 3 5").is_err());
+        }
+
+        #[test]
+        fn fails_to_spell_notes_past_two_accidentals() {
+            let d_flat = Note::try_from("Db").unwrap();
+            assert!(Scale::try_new(d_flat, custom("1 1 1 2 2 2 3"), Ascending).is_err());
+            assert!(Scale::try_new(d_flat, custom("2 2 1 2 2 2 1"), Ascending).is_ok());
+        }
+    }
+
+    #[test]
+    fn fails_to_spell_notes_past_two_accidentals() {
+        let f_flat = Note::try_from("Fb").unwrap();
+        assert_eq!(
+            Scale::try_new(f_flat, ScaleKind::Altered, ScaleDirection::Ascending),
+            Err(
+                "The altered scale on Fb can't be spelled with at most two accidentals".to_string()
+            )
+        );
+        let e = Note::try_from("E").unwrap();
+        let altered = Scale::try_new(e, ScaleKind::Altered, ScaleDirection::Ascending).unwrap();
+        assert_eq!(altered.print(), "E F G Ab Bb C D E");
+    }
+}
+
+#[cfg(test)]
+mod scale_harmonization_test {
+    use super::*;
+    use ScaleKind::*;
+
+    fn chord_names(chords: Result<Vec<Option<Chord>>, String>) -> Vec<String> {
+        chords
+            .unwrap()
+            .iter()
+            .map(|chord| chord.as_ref().map_or("-".to_string(), Chord::name))
+            .collect()
+    }
+
+    fn scale(root_note_str: &str, kind: ScaleKind) -> Scale {
+        Scale::new(
+            Note::try_from(root_note_str).unwrap(),
+            kind,
+            ScaleDirection::Ascending,
+        )
+    }
+
+    #[test]
+    fn harmonizes_major_scale_in_triads() {
+        assert_eq!(
+            chord_names(scale("C", Major).triads()),
+            ["C", "Dm", "Em", "F", "G", "Am", "Bdim"]
+        );
+        assert_eq!(
+            chord_names(scale("Eb", Major).triads()),
+            ["Eb", "Fm", "Gm", "Ab", "Bb", "Cm", "Ddim"]
+        );
+    }
+
+    #[test]
+    fn harmonizes_minor_scales_in_triads() {
+        assert_eq!(
+            chord_names(scale("A", HarmonicMinor).triads()),
+            ["Am", "Bdim", "Caug", "Dm", "E", "F", "G#dim"]
+        );
+        assert_eq!(
+            chord_names(scale("A", MelodicMinor).triads()),
+            ["Am", "Bm", "Caug", "D", "E", "F#dim", "G#dim"]
+        );
+    }
+
+    #[test]
+    fn harmonizes_scales_in_seventh_chords() {
+        assert_eq!(
+            chord_names(scale("C", Major).seventh_chords()),
+            ["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bm7b5"]
+        );
+        assert_eq!(
+            chord_names(scale("C", HarmonicMinor).seventh_chords()),
+            ["Cm(maj7)", "Dm7b5", "Ebmaj7#5", "Fm7", "G7", "Abmaj7", "Bdim7"]
+        );
+    }
+
+    #[test]
+    fn harmonizes_descending_scales_from_their_ascending_form() {
+        let descending = Scale::new(
+            Note::try_from("D").unwrap(),
+            Dorian,
+            ScaleDirection::Descending,
+        );
+        assert_eq!(
+            chord_names(descending.triads()),
+            ["Dm", "Em", "F", "G", "Am", "Bdim", "C"]
+        );
+    }
+
+    #[test]
+    fn harmonizes_every_degree_it_can() {
+        assert_eq!(
+            chord_names(scale("C", HungarianMinor).triads()),
+            ["Cm", "D(b5)", "Ebaug", "-", "G", "Ab", "Bm"]
+        );
+        assert_eq!(
+            chord_names(scale("C", DoubleHarmonic).seventh_chords()),
+            ["Cmaj7", "Dbmaj7", "-", "Fm(maj7)", "G7b5", "Abmaj7#5", "-"]
+        );
+    }
+
+    #[test]
+    fn harmonizes_every_degree_of_major_and_minor_modes() {
+        for kind in [
+            Major,
+            Minor,
+            Ionian,
+            Dorian,
+            Phrygian,
+            Lydian,
+            Mixolydian,
+            Aeolian,
+            Locrian,
+            MelodicMinor,
+            DorianFlatTwo,
+            LydianAugmented,
+            LydianDominant,
+            MixolydianFlatSix,
+            LocrianSharpTwo,
+            Altered,
+            HarmonicMinor,
+            LocrianSharpSix,
+            IonianSharpFive,
+            DorianSharpFour,
+            PhrygianDominant,
+            LydianSharpTwo,
+            SuperLocrianDoubleFlatSeven,
+        ] {
+            let c = scale("C", kind.clone());
+            assert!(c.triads().unwrap().iter().all(Option::is_some), "{kind}");
+            assert!(
+                c.seventh_chords().unwrap().iter().all(Option::is_some),
+                "{kind}"
+            );
+        }
+    }
+
+    #[test]
+    fn only_harmonizes_heptatonic_scales() {
+        assert!(scale("C", MajorPentatonic).triads().is_err());
+        assert!(scale("C", MinorPentatonic).seventh_chords().is_err());
+    }
 }
@@ -0,0 +1,290 @@
+use crate::note::NotePitchInterval::{self, *};
+
+// the ascending steps of the built-in scales, from the root up to the octave.
+// modes are rotations of their parent scale, and the blues, bebop and world scales list the degrees they're spelled from.
+
+pub(super) const IONIAN: [NotePitchInterval; 7] = [
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+];
+pub(super) const DORIAN: [NotePitchInterval; 7] = mode(IONIAN, 2);
+pub(super) const PHRYGIAN: [NotePitchInterval; 7] = mode(IONIAN, 3);
+pub(super) const LYDIAN: [NotePitchInterval; 7] = mode(IONIAN, 4);
+pub(super) const MIXOLYDIAN: [NotePitchInterval; 7] = mode(IONIAN, 5);
+pub(super) const AEOLIAN: [NotePitchInterval; 7] = mode(IONIAN, 6);
+pub(super) const LOCRIAN: [NotePitchInterval; 7] = mode(IONIAN, 7);
+
+pub(super) const MELODIC_MINOR: [NotePitchInterval; 7] = [
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+];
+pub(super) const DORIAN_FLAT_TWO: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 2);
+pub(super) const LYDIAN_AUGMENTED: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 3);
+pub(super) const LYDIAN_DOMINANT: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 4);
+pub(super) const MIXOLYDIAN_FLAT_SIX: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 5);
+pub(super) const LOCRIAN_SHARP_TWO: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 6);
+pub(super) const ALTERED: [NotePitchInterval; 7] = mode(MELODIC_MINOR, 7);
+
+pub(super) const HARMONIC_MINOR: [NotePitchInterval; 7] = [
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+pub(super) const LOCRIAN_SHARP_SIX: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 2);
+pub(super) const IONIAN_SHARP_FIVE: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 3);
+pub(super) const DORIAN_SHARP_FOUR: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 4);
+pub(super) const PHRYGIAN_DOMINANT: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 5);
+pub(super) const LYDIAN_SHARP_TWO: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 6);
+pub(super) const SUPER_LOCRIAN_DOUBLE_FLAT_SEVEN: [NotePitchInterval; 7] = mode(HARMONIC_MINOR, 7);
+
+pub(super) const MAJOR_PENTATONIC: [NotePitchInterval; 5] = [
+    MajorSecond,
+    MajorSecond,
+    MinorThird,
+    MajorSecond,
+    MinorThird,
+];
+pub(super) const MINOR_PENTATONIC: [NotePitchInterval; 5] = mode(MAJOR_PENTATONIC, 5);
+
+pub(super) const HALF_WHOLE: [NotePitchInterval; 8] = [
+    MinorSecond,
+    MajorSecond,
+    AugmentedUnison,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+];
+pub(super) const WHOLE_HALF: [NotePitchInterval; 8] = [
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    AugmentedUnison,
+    MajorSecond,
+    MinorSecond,
+];
+pub(super) const WHOLE_TONE: [NotePitchInterval; 6] = [
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    DiminishedThird,
+];
+pub(super) const AUGMENTED: [NotePitchInterval; 6] = [
+    AugmentedSecond,
+    MinorSecond,
+    MinorThird,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+
+// 1 b3 4 b5 5 b7
+pub(super) const MINOR_BLUES: [NotePitchInterval; 6] = [
+    MinorThird,
+    MajorSecond,
+    MinorSecond,
+    AugmentedUnison,
+    MinorThird,
+    MajorSecond,
+];
+// 1 2 b3 3 5 6
+pub(super) const MAJOR_BLUES: [NotePitchInterval; 6] = [
+    MajorSecond,
+    MinorSecond,
+    AugmentedUnison,
+    MinorThird,
+    MajorSecond,
+    MinorThird,
+];
+// 1 2 3 4 5 6 b7 7
+pub(super) const BEBOP_DOMINANT: [NotePitchInterval; 8] = [
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    AugmentedUnison,
+    MinorSecond,
+];
+// 1 2 3 4 5 #5 6 7
+pub(super) const BEBOP_MAJOR: [NotePitchInterval; 8] = [
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    AugmentedUnison,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+];
+// 1 2 b3 3 4 5 6 b7
+pub(super) const BEBOP_MINOR: [NotePitchInterval; 8] = [
+    MajorSecond,
+    MinorSecond,
+    AugmentedUnison,
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+];
+
+// 1 2 b3 #4 5 b6 7
+pub(super) const HUNGARIAN_MINOR: [NotePitchInterval; 7] = [
+    MajorSecond,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+// 1 #2 3 #4 5 6 b7
+pub(super) const HUNGARIAN_MAJOR: [NotePitchInterval; 7] = [
+    AugmentedSecond,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+    MajorSecond,
+];
+// 1 b2 3 4 5 b6 7
+pub(super) const DOUBLE_HARMONIC: [NotePitchInterval; 7] = [
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+    MajorSecond,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+// 1 b2 b3 4 5 6 7
+pub(super) const NEAPOLITAN_MAJOR: [NotePitchInterval; 7] = [
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+];
+// 1 b2 b3 4 5 b6 7
+pub(super) const NEAPOLITAN_MINOR: [NotePitchInterval; 7] = [
+    MinorSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+// 1 b2 3 #4 #5 #6 7
+pub(super) const ENIGMATIC: [NotePitchInterval; 7] = [
+    MinorSecond,
+    AugmentedSecond,
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorSecond,
+    MinorSecond,
+];
+// 1 b2 3 4 b5 b6 7
+pub(super) const PERSIAN: [NotePitchInterval; 7] = [
+    MinorSecond,
+    AugmentedSecond,
+    MinorSecond,
+    MinorSecond,
+    MajorSecond,
+    AugmentedSecond,
+    MinorSecond,
+];
+
+// 1 2 b3 5 b6
+pub(super) const HIRAJOSHI: [NotePitchInterval; 5] = [
+    MajorSecond,
+    MinorSecond,
+    MajorThird,
+    MinorSecond,
+    MajorThird,
+];
+// 1 b2 4 5 b7
+pub(super) const IN_SEN: [NotePitchInterval; 5] = [
+    MinorSecond,
+    MajorThird,
+    MajorSecond,
+    MinorThird,
+    MajorSecond,
+];
+// 1 b2 4 b5 b7
+pub(super) const IWATO: [NotePitchInterval; 5] = [
+    MinorSecond,
+    MajorThird,
+    MinorSecond,
+    MajorThird,
+    MajorSecond,
+];
+// 1 2 b3 5 6
+pub(super) const KUMOI: [NotePitchInterval; 5] = [
+    MajorSecond,
+    MinorSecond,
+    MajorThird,
+    MajorSecond,
+    MinorThird,
+];
+// 1 b2 b3 5 b6
+pub(super) const PELOG: [NotePitchInterval; 5] = [
+    MinorSecond,
+    MajorSecond,
+    MajorThird,
+    MinorSecond,
+    MajorThird,
+];
+// 1 2 4 5 b7
+pub(super) const EGYPTIAN: [NotePitchInterval; 5] = [
+    MajorSecond,
+    MinorThird,
+    MajorSecond,
+    MinorThird,
+    MajorSecond,
+];
+// 1 2 3 #4 6 b7
+pub(super) const PROMETHEUS: [NotePitchInterval; 6] = [
+    MajorSecond,
+    MajorSecond,
+    MajorSecond,
+    MinorThird,
+    MinorSecond,
+    MajorSecond,
+];
+
+// the steps rotated to start on the nth degree, counting the root as 1
+const fn mode<const N: usize>(steps: [NotePitchInterval; N], n: usize) -> [NotePitchInterval; N] {
+    let mut result = steps;
+    let mut index = 0;
+    while index < N {
+        result[index] = steps[(index + n - 1) % N];
+        index += 1;
+    }
+    result
+}
@@ -0,0 +1,400 @@
+use crate::chord::Chord;
+use crate::harmony::Key;
+use crate::note::{Note, NotePitchInterval, Pitch};
+use crate::scale::Scale;
+
+// how the new root is spelled when transposing by a number of semitones.
+// notes without an accidental are always preferred, and the other tones follow the root's spelling.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Spelling {
+    Sharps,
+    Flats,
+    // the spelling of the most common key signature, i.e. Db, Eb, F#, Ab and Bb
+    Simplest,
+}
+
+impl Spelling {
+    // the note spelled this way for the pitch class, where C is 0
+    pub fn spell(&self, pitch_class: u8) -> Note {
+        const SHARPS: [&str; 12] = [
+            "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+        ];
+        const FLATS: [&str; 12] = [
+            "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+        ];
+        const SIMPLEST: [&str; 12] = [
+            "C", "Db", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B",
+        ];
+        let names = match self {
+            Spelling::Sharps => SHARPS,
+            Spelling::Flats => FLATS,
+            Spelling::Simplest => SIMPLEST,
+        };
+        Note::try_from(names[pitch_class as usize % 12]).unwrap()
+    }
+}
+
+// moves notes, pitches, chords, scales, keys and sequences of them by the same interval.
+// every tone moves by the same interval, so the spelling stays coherent, e.g. C E G up a major second is D F# A.
+// transposing fails when a tone would need more than two accidentals.
+pub trait Transpose: Sized {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String>;
+
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String>;
+
+    // the note that decides the spelling when transposing by semitones, e.g. the root of a chord.
+    // None when there's nothing to transpose.
+    fn spelling_anchor(&self) -> Option<Note>;
+
+    // moves pitches by whole octaves. everything without an octave stays as it is.
+    // fails when a pitch would go past octave -128 or 127.
+    fn transpose_octaves(self, _octaves: i8) -> Result<Self, String> {
+        Ok(self)
+    }
+
+    // moves by a number of semitones, going down for negative numbers.
+    // the anchor is spelled with the policy, and everything else moves by the same interval as the anchor.
+    // when the policy's spelling is no simple interval away from the anchor, like F from E#, the anchor takes
+    // the enharmonic spelling with the fewest accidentals that is, so E# moved by 0 semitones stays E#.
+    fn transpose_semitones(&self, semitones: i16, spelling: Spelling) -> Result<Self, String> {
+        let anchor = match self.spelling_anchor() {
+            Some(anchor) => anchor,
+            None => return self.transpose_up(NotePitchInterval::PerfectUnison),
+        };
+        let pitch_class = (anchor.pitch_class() as i16 + semitones).rem_euclid(12) as u8;
+        let target = spelling.spell(pitch_class);
+        let interval = match anchor.interval_to(&target) {
+            Some(interval) => interval,
+            None => target
+                .enharmonics()
+                .filter_map(|note| Some((note, anchor.interval_to(&note)?)))
+                .min_by_key(|(note, _)| i8::from(note.pitch_variant()).abs())
+                .map(|(_, interval)| interval)
+                .ok_or(format!(
+                    "{anchor} can't be transposed to {target} by a simple interval"
+                ))?,
+        };
+        let octaves = i8::try_from(semitones.div_euclid(12))
+            .map_err(|_| format!("{semitones} semitones is more octaves than a pitch can move"))?;
+        self.transpose_up(interval)?.transpose_octaves(octaves)
+    }
+
+    // moves from one key to another by the interval between their tonics, going up.
+    // the mode isn't changed, so a melody in C major moved to A minor ends up in A major.
+    fn transpose_to_key(&self, from: &Key, to: &Key) -> Result<Self, String> {
+        let interval = from.tonic().interval_to(&to.tonic()).ok_or(format!(
+            "There's no simple interval from {} to {}",
+            from.tonic(),
+            to.tonic()
+        ))?;
+        self.transpose_up(interval)
+    }
+}
+
+fn moved(note: Note, interval: NotePitchInterval, ascending: bool) -> Result<Note, String> {
+    let interval = if ascending {
+        interval
+    } else {
+        interval.invert()
+    };
+    note.checked_by_interval_ascending(interval).ok_or(format!(
+        "{note} moved by {interval:?} can't be spelled with at most two accidentals"
+    ))
+}
+
+impl Transpose for Note {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        moved(*self, interval, true)
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        moved(*self, interval, false)
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        Some(*self)
+    }
+}
+
+impl Transpose for Pitch {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        moved(self.note(), interval, true)?;
+        self.checked_by_interval_ascending(interval).ok_or(format!(
+            "{self} moved up by {interval:?} goes past octave 127"
+        ))
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        moved(self.note(), interval, false)?;
+        self.checked_by_interval_descending(interval).ok_or(format!(
+            "{self} moved down by {interval:?} goes past octave -128"
+        ))
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        Some(self.note())
+    }
+    fn transpose_octaves(self, octaves: i8) -> Result<Self, String> {
+        self.checked_by_octaves(octaves).ok_or(format!(
+            "{self} moved by {octaves} octaves goes past octave -128 or 127"
+        ))
+    }
+}
+
+impl Transpose for Chord {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        transpose_chord(self, interval, true)
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        transpose_chord(self, interval, false)
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        Some(self.root())
+    }
+}
+
+fn transpose_chord(
+    chord: &Chord,
+    interval: NotePitchInterval,
+    ascending: bool,
+) -> Result<Chord, String> {
+    let root = moved(chord.root(), interval, ascending)?;
+    let bass = match chord.bass() == chord.root() {
+        true => None,
+        false => Some(moved(chord.bass(), interval, ascending)?),
+    };
+    chord.moved_to(root, bass).ok_or(format!(
+        "{} moved to {root} can't be spelled with at most two accidentals",
+        chord.name()
+    ))
+}
+
+impl Transpose for Scale {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        transpose_scale(self, interval, true)
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        transpose_scale(self, interval, false)
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        Some(self.root())
+    }
+}
+
+fn transpose_scale(
+    scale: &Scale,
+    interval: NotePitchInterval,
+    ascending: bool,
+) -> Result<Scale, String> {
+    let root = moved(scale.root(), interval, ascending)?;
+    Scale::try_new(root, scale.kind().clone(), scale.direction())
+}
+
+impl Transpose for Key {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        Ok(Key::new(moved(self.tonic(), interval, true)?, self.mode()))
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        Ok(Key::new(moved(self.tonic(), interval, false)?, self.mode()))
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        Some(self.tonic())
+    }
+}
+
+// a sequence moves as a whole, so transposing by semitones spells its first element with the policy
+impl<T: Transpose> Transpose for Vec<T> {
+    fn transpose_up(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        self.iter()
+            .map(|item| item.transpose_up(interval))
+            .collect()
+    }
+    fn transpose_down(&self, interval: NotePitchInterval) -> Result<Self, String> {
+        self.iter()
+            .map(|item| item.transpose_down(interval))
+            .collect()
+    }
+    fn spelling_anchor(&self) -> Option<Note> {
+        self.first().and_then(|item| item.spelling_anchor())
+    }
+    fn transpose_octaves(self, octaves: i8) -> Result<Self, String> {
+        self.into_iter()
+            .map(|item| item.transpose_octaves(octaves))
+            .collect()
+    }
+}
+
+////////////////
+// UNIT TESTS //
+////////////////
+
+#[cfg(test)]
+mod transpose_test {
+    use super::*;
+    use crate::chord::{ChordQuality, ChordSeventh};
+    use crate::harmony::KeyMode;
+    use crate::scale::{ScaleDirection, ScaleKind};
+    use NotePitchInterval::*;
+
+    fn note(name: &str) -> Note {
+        Note::try_from(name).unwrap()
+    }
+
+    fn notes(names: &str) -> Vec<Note> {
+        names.split(' ').map(note).collect()
+    }
+
+    fn pitches(names: &str) -> Vec<Pitch> {
+        names
+            .split(' ')
+            .map(|name| Pitch::try_from(name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn transposes_notes_and_pitches_by_interval() {
+        assert_eq!(note("C").transpose_up(MajorThird), Ok(note("E")));
+        assert_eq!(note("C").transpose_down(MajorThird), Ok(note("Ab")));
+        assert_eq!(
+            pitches("B3 C4").transpose_up(MajorSecond),
+            Ok(pitches("C#4 D4"))
+        );
+        assert_eq!(pitches("C4").transpose_down(MinorSecond), Ok(pitches("B3")));
+        assert!(note("B##").transpose_up(AugmentedFourth).is_err());
+    }
+
+    #[test]
+    fn transposes_by_semitones_with_a_spelling() {
+        assert_eq!(
+            note("C").transpose_semitones(1, Spelling::Sharps),
+            Ok(note("C#"))
+        );
+        assert_eq!(
+            note("C").transpose_semitones(1, Spelling::Flats),
+            Ok(note("Db"))
+        );
+        assert_eq!(
+            note("C").transpose_semitones(6, Spelling::Simplest),
+            Ok(note("F#"))
+        );
+        assert_eq!(
+            notes("C E G").transpose_semitones(3, Spelling::Simplest),
+            Ok(notes("Eb G Bb"))
+        );
+        assert_eq!(
+            pitches("C4 E4").transpose_semitones(-1, Spelling::Simplest),
+            Ok(pitches("B3 D#4"))
+        );
+        assert_eq!(
+            pitches("A4").transpose_semitones(14, Spelling::Simplest),
+            Ok(pitches("B5"))
+        );
+        assert_eq!(
+            Vec::<Note>::new().transpose_semitones(2, Spelling::Sharps),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn respells_when_the_policy_spelling_is_out_of_reach() {
+        assert_eq!(
+            note("E#").transpose_semitones(0, Spelling::Sharps),
+            Ok(note("E#"))
+        );
+        assert_eq!(
+            pitches("B#3").transpose_semitones(12, Spelling::Simplest),
+            Ok(pitches("B#4"))
+        );
+        assert_eq!(
+            note("Cb").transpose_semitones(0, Spelling::Sharps),
+            Ok(note("Cb"))
+        );
+        assert_eq!(
+            note("Fb").transpose_semitones(-12, Spelling::Flats),
+            Ok(note("Fb"))
+        );
+        // the policy's spelling is kept whenever it can be reached
+        assert_eq!(
+            note("E#").transpose_semitones(1, Spelling::Sharps),
+            Ok(note("F#"))
+        );
+        assert_eq!(
+            note("Cb").transpose_semitones(2, Spelling::Flats),
+            Ok(note("Db"))
+        );
+    }
+
+    #[test]
+    fn transposes_chords_with_their_bass() {
+        let chord = Chord::new(note("C"), ChordQuality::Major)
+            .with_seventh(ChordSeventh::Minor)
+            .with_bass(note("E"));
+        let transposed = chord.transpose_up(MajorSecond).unwrap();
+        assert_eq!(transposed.name(), "D7/F#");
+        assert_eq!(transposed.notes(), notes("F# A C D"));
+
+        let transposed = chord.transpose_semitones(-2, Spelling::Simplest).unwrap();
+        assert_eq!(transposed.name(), "Bb7/D");
+    }
+
+    #[test]
+    fn transposes_scales_and_keys() {
+        let scale = Scale::new(
+            note("A"),
+            ScaleKind::HarmonicMinor,
+            ScaleDirection::Descending,
+        );
+        let transposed = scale.transpose_up(PerfectFourth).unwrap();
+        assert_eq!(transposed.print(), "D C# Bb A G F E D");
+        assert_eq!(transposed.kind(), &ScaleKind::HarmonicMinor);
+
+        let key = Key::new(note("E"), KeyMode::Minor);
+        let transposed = key.transpose_semitones(1, Spelling::Flats).unwrap();
+        assert_eq!(transposed.tonic(), note("F"));
+        assert_eq!(transposed.mode(), KeyMode::Minor);
+    }
+
+    #[test]
+    fn transposes_to_another_key() {
+        let c_major = Key::new(note("C"), KeyMode::Major);
+        let e_flat_major = Key::new(note("Eb"), KeyMode::Major);
+        let progression = vec![
+            Chord::new(note("C"), ChordQuality::Major),
+            Chord::new(note("A"), ChordQuality::Minor),
+            Chord::new(note("G"), ChordQuality::Major).with_seventh(ChordSeventh::Minor),
+        ];
+        let names: Vec<String> = progression
+            .transpose_to_key(&c_major, &e_flat_major)
+            .unwrap()
+            .iter()
+            .map(|chord| chord.name().to_string())
+            .collect();
+        assert_eq!(names, ["Eb", "Cm", "Bb7"]);
+        assert_eq!(
+            notes("E G").transpose_to_key(&c_major, &e_flat_major),
+            Ok(notes("G Bb"))
+        );
+    }
+
+    #[test]
+    fn stays_within_the_octaves_a_pitch_can_be_in() {
+        let pitch = |name: &str| Pitch::try_from(name).unwrap();
+        assert!(pitch("B127").transpose_up(MajorSecond).is_err());
+        assert_eq!(pitch("A127").transpose_up(MajorSecond), Ok(pitch("B127")));
+        assert!(pitch("C-128").transpose_down(MinorSecond).is_err());
+        assert_eq!(
+            pitch("D-128").transpose_down(MajorSecond),
+            Ok(pitch("C-128"))
+        );
+        assert!(pitch("C4")
+            .transpose_semitones(3072, Spelling::Sharps)
+            .is_err());
+        assert!(pitch("C4")
+            .transpose_semitones(1560, Spelling::Sharps)
+            .is_err());
+        assert_eq!(
+            pitch("C4").transpose_semitones(1476, Spelling::Sharps),
+            Ok(pitch("C127"))
+        );
+        assert!(pitch("C4")
+            .transpose_semitones(i16::MIN, Spelling::Sharps)
+            .is_err());
+        assert!(pitches("C4 C120").transpose_octaves(8).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+// building scales and chords, and displaying them, shouldn't touch the heap.
+// the counting allocator replaces the global one for this test binary only.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+use audiate::chord::{ChordAlteration, ChordExtensionKind, ChordSeventh};
+use audiate::scale::{ScaleDirection, ScaleKind};
+use audiate::{Chord, ChordQuality, Note, NotePitchInterval, Scale};
+
+struct CountingAllocator;
+
+// counts the allocations made on the current thread, so tests running in parallel don't interfere
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations(build: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    build();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn altered_dominant(root: Note) -> Chord {
+    Chord::new(root, ChordQuality::Major)
+        .with_seventh(ChordSeventh::Minor)
+        .with_extension(ChordExtensionKind::Thirteenth)
+        .with_alteration(ChordAlteration::SharpEleventh)
+        .with_alteration(ChordAlteration::FlatNinth)
+        .with_alteration(ChordAlteration::SharpNinth)
+        .without_fifth()
+}
+
+#[test]
+fn builds_scales_without_allocating() {
+    let root = Note::try_from("Eb").unwrap();
+    let count = allocations(|| {
+        for kind in ScaleKind::built_in() {
+            let scale = Scale::new(root, kind.clone(), ScaleDirection::AscendingDescending);
+            assert!(!scale.tones().is_empty());
+            assert!(kind.intervals_iter(ScaleDirection::Descending).len() > 0);
+        }
+    });
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn builds_chords_without_allocating() {
+    let root = Note::try_from("F#").unwrap();
+    let bass = Note::try_from("A#").unwrap();
+    let count = allocations(|| {
+        let chord = altered_dominant(root).with_bass(bass);
+        assert_eq!(chord.intervals().len(), 6);
+        assert_eq!(chord.notes_iter().count(), 7);
+    });
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn displays_scales_without_building_strings() {
+    let scale = Scale::new(
+        Note::try_from("A").unwrap(),
+        ScaleKind::MelodicMinor,
+        ScaleDirection::AscendingDescending,
+    );
+    let mut buffer = String::with_capacity(64);
+    let count = allocations(|| write!(buffer, "{scale}").unwrap());
+    assert_eq!(count, 0);
+    assert_eq!(buffer, "A B C D E F# G# A G F E D C B A");
+}
+
+#[test]
+fn displays_chords_without_building_strings() {
+    let chords = [
+        altered_dominant(Note::try_from("F#").unwrap()).with_bass(Note::try_from("A#").unwrap()),
+        Chord::new(Note::try_from("C").unwrap(), ChordQuality::Minor)
+            .with_added_tone(NotePitchInterval::MajorSecond),
+    ];
+    let mut buffer = String::with_capacity(64);
+    let count = allocations(|| {
+        for chord in &chords {
+            write!(buffer, "{chord} ").unwrap();
+        }
+    });
+    assert_eq!(count, 0);
+    assert_eq!(buffer, "F#13b9#9#11(no5)/A# Cm(add9) ");
+}